@@ -0,0 +1,123 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// A puzzle answer. Integers compare by value whatever their width, so an
+/// `Unsigned(4)` equals a `Signed(4)`, and text only ever equals text.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(i128),
+    Text(String),
+}
+
+impl Answer {
+    /// The answer's value, if it is an integer.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n as i128),
+            Answer::Unsigned(n) => Some(*n as i128),
+            Answer::Big(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => match (self.as_integer(), other.as_integer()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Reads an answer back from its text, as saved in `answers.toml` or a
+/// sample manifest. Anything that isn't an integer is kept as text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = s.parse::<i64>() {
+            return Ok(Answer::Signed(n));
+        }
+        if let Ok(n) = s.parse::<u64>() {
+            return Ok(Answer::Unsigned(n));
+        }
+        match s.parse::<i128>() {
+            Ok(n) => Ok(Answer::Big(n)),
+            Err(_) => Ok(Answer::Text(s.to_string())),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident($inner:ty): $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $inner)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(Signed(i64): i8, i16, i32, i64, isize);
+from_integer!(Unsigned(u64): u8, u16, u32, u64, usize);
+from_integer!(Big(i128): i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equality() {
+        assert_eq!(Answer::from(4i64), Answer::from(4u64));
+        assert_eq!(Answer::from(4u32), Answer::from(4i128));
+        assert_ne!(Answer::from(4i64), Answer::from(5i64));
+        assert_ne!(Answer::from(4i64), Answer::from("4"));
+        assert_eq!(Answer::from("EZFC"), Answer::from("EZFC".to_string()));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let answers = [
+            Answer::from(-3i64),
+            Answer::from(u64::MAX),
+            Answer::from(i128::MIN),
+            Answer::from("a,b"),
+        ];
+        for expected in answers {
+            let actual = expected.to_string().parse::<Answer>().unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+}
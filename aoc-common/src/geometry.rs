@@ -0,0 +1,180 @@
+use std::ops::{Add, Sub};
+
+use crate::math::gcd;
+
+/// A position on the integer plane, with `y` growing downwards like the rows
+/// of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// Distance moving only horizontally and vertically.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance moving diagonally as well, like a king in chess.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The four points sharing an edge with this one.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The eight points sharing an edge or a corner with this one.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|offset| *offset != Point::ORIGIN)
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+/// One of the four ways to move on a grid. Up is towards smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Twice the area enclosed by the polygon through `vertices`, by the
+/// shoelace formula. Doubling keeps the result exact for lattice polygons,
+/// whose area can end in a half.
+pub fn double_area(vertices: &[Point]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(a, b)| a.x * b.y - a.y * b.x).sum::<i64>().abs()
+}
+
+/// The number of lattice points on the edges of the polygon through
+/// `vertices`. For a loop of neighbouring cells that is the number of cells.
+pub fn boundary_points(vertices: &[Point]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges
+        .map(|(a, b)| gcd((a.x - b.x).abs(), (a.y - b.y).abs()))
+        .sum()
+}
+
+/// The number of lattice points strictly inside the polygon through
+/// `vertices`, by Pick's theorem: `A = i + b/2 - 1`.
+pub fn interior_points(vertices: &[Point]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices)) / 2 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, 6);
+        let b = Point::new(5, 11);
+        assert_eq!(9, a.manhattan(b));
+        assert_eq!(5, a.chebyshev(b));
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Up, Direction::Down.reverse());
+        assert_eq!(Point::new(2, 4), Point::new(2, 3).step(Direction::Down));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point::new(0, 0);
+        assert_eq!(4, p.neighbours4().count());
+        assert!(p.neighbours8().all(|n| n != p && p.chebyshev(n) == 1));
+        assert_eq!(8, p.neighbours8().count());
+    }
+
+    #[test]
+    fn test_polygon() {
+        // A 4x3 rectangle of lattice points, listed as corners only.
+        let corners = [
+            Point::new(0, 0),
+            Point::new(3, 0),
+            Point::new(3, 2),
+            Point::new(0, 2),
+        ];
+        assert_eq!(12, double_area(&corners));
+        assert_eq!(10, boundary_points(&corners));
+        assert_eq!(2, interior_points(&corners));
+
+        let triangle = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 3)];
+        assert_eq!(12, double_area(&triangle));
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, Point, PuzzleInput};
+
+/// A rectangular map of cells, addressed by `(x, y)` with `(0, 0)` at the top
+/// left and `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells, row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells don't fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads one row per input line, turning each character into a cell with
+    /// `cell`. A character it rejects is reported as not being `expected`, and
+    /// every row must be as wide as the first.
+    pub fn parse(
+        input: &PuzzleInput,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let mut count = 0;
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| line.error(x, expected, format!("'{}'", c)))?;
+                cells.push(value);
+                count += 1;
+            }
+
+            match width {
+                Some(width) if width != count => {
+                    return Err(line.error(
+                        0,
+                        format!("{} columns", width),
+                        format!("{} columns", count),
+                    ))
+                }
+                _ => width = Some(count),
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` off the edge of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cell at `point`, or `None` off the edge of the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        self.get(x, y)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The positions directly above, below, left and right of `(x, y)` that
+    /// are on the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &[(0, -1), (-1, 0), (1, 0), (0, 1)])
+    }
+
+    /// Like [`Grid::neighbours4`], diagonals included.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(
+            x,
+            y,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flatten().cloned().collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.collect::<Vec<&T>>().into_iter().rev())
+            .cloned()
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x))
+            .cloned()
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is off the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is off the grid", x, y))
+    }
+}
+
+/// Writes the grid back out as text, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(&PuzzleInput::new("grid.txt", text), "a cell", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid("ab\ncd\nef\n");
+        assert_eq!((2, 3), (g.width(), g.height()));
+        assert_eq!(Some(&'d'), g.get(1, 1));
+        assert_eq!(None, g.get(2, 1));
+        assert_eq!('e', g[(0, 2)]);
+        assert_eq!(Some(&'c'), g.at(Point::new(0, 1)));
+        assert_eq!(None, g.at(Point::new(-1, 1)));
+        assert_eq!("ab\ncd\nef\n", g.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = PuzzleInput::new("grid.txt", "..#\n.x.\n");
+        let actual = Grid::parse(&input, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        });
+        let expected = "grid.txt:2:2: expected '#' or '.', found 'x'";
+        assert_eq!(expected, actual.unwrap_err().to_string());
+
+        let input = PuzzleInput::new("grid.txt", "...\n..\n");
+        let actual = Grid::parse(&input, "a cell", Some);
+        let expected = "grid.txt:2:1: expected 3 columns, found 2 columns";
+        assert_eq!(expected, actual.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef\nghi\n");
+        let actual = g.neighbours4(0, 0).collect::<Vec<(usize, usize)>>();
+        assert_eq!(vec![(1, 0), (0, 1)], actual);
+        assert_eq!(8, g.neighbours8(1, 1).count());
+        assert_eq!(5, g.neighbours8(1, 2).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let g = grid("abc\ndef\n");
+        let rows = g
+            .rows()
+            .map(|r| r.iter().collect())
+            .collect::<Vec<String>>();
+        assert_eq!(vec!["abc", "def"], rows);
+        let columns = g.columns().map(|c| c.collect()).collect::<Vec<String>>();
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let g = grid("abc\ndef\n");
+        assert_eq!("ad\nbe\ncf\n", g.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", g.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad\n", g.rotate_anticlockwise().to_string());
+        assert_eq!(g, g.rotate_clockwise().rotate_anticlockwise());
+    }
+}
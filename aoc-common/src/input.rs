@@ -0,0 +1,440 @@
+use std::{
+    fmt::Display,
+    ops::{Deref, Range},
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+
+use crate::ParseError;
+
+/// How a day wants its input split into lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputMode {
+    /// Blank lines are dropped and the rest have their surrounding whitespace
+    /// removed.
+    #[default]
+    Trimmed,
+    /// Every line is kept exactly as written, blank ones included, for
+    /// puzzles where spaces or column alignment carry meaning.
+    Raw,
+}
+
+/// Puzzle input, split into lines according to its [`InputMode`]. Each line
+/// remembers where it came from so parsers can point at the exact spot they
+/// choke on, and blank lines separate the lines into
+/// [`blocks`](PuzzleInput::blocks). Whatever the mode, a leading byte order
+/// mark is dropped and CRLF line endings become LF.
+pub struct PuzzleInput {
+    source: String,
+    text: String,
+    mode: InputMode,
+    lines: Vec<Entry>,
+}
+
+struct Entry {
+    number: usize,
+    indent: usize,
+    span: Range<usize>,
+}
+
+impl PuzzleInput {
+    /// Wraps `text` in [`InputMode::Trimmed`], naming it `source` in error
+    /// messages.
+    pub fn new(source: impl Into<String>, text: &str) -> Self {
+        PuzzleInput::with_mode(source, text, InputMode::Trimmed)
+    }
+
+    pub fn with_mode(source: impl Into<String>, text: &str, mode: InputMode) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let text = text.replace("\r\n", "\n");
+
+        let mut lines = vec![];
+        let mut start = 0;
+        for (i, raw) in text.split_inclusive('\n').enumerate() {
+            let raw_start = start;
+            start += raw.len();
+            let raw = raw.strip_suffix('\n').unwrap_or(raw);
+            let entry = match mode {
+                InputMode::Raw => Entry {
+                    number: i + 1,
+                    indent: 0,
+                    span: raw_start..raw_start + raw.len(),
+                },
+                InputMode::Trimmed if raw.trim().is_empty() => continue,
+                InputMode::Trimmed => {
+                    let leading = raw.len() - raw.trim_start().len();
+                    Entry {
+                        number: i + 1,
+                        indent: raw[..leading].chars().count(),
+                        span: raw_start + leading..raw_start + raw.trim_end().len(),
+                    }
+                }
+            };
+            lines.push(entry);
+        }
+
+        PuzzleInput {
+            source: source.into(),
+            text,
+            mode,
+            lines,
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+        let text = String::from_utf8(bytes)
+            .with_context(|| format!("{} is not valid UTF-8", path.display()))?;
+        Ok(PuzzleInput::new(path.display().to_string(), &text))
+    }
+
+    /// The same input split up again for `mode`.
+    pub fn to_mode(&self, mode: InputMode) -> PuzzleInput {
+        PuzzleInput::with_mode(self.source.clone(), &self.text, mode)
+    }
+
+    pub fn mode(&self) -> InputMode {
+        self.mode
+    }
+
+    /// The whole input, with only the byte order mark and line endings
+    /// normalized.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines.iter().map(|entry| Line {
+            source: &self.source,
+            number: entry.number,
+            indent: entry.indent,
+            text: &self.text[entry.span.clone()],
+        })
+    }
+
+    /// The lines in groups separated by one or more blank lines, for inputs
+    /// made of paragraphs. Every block has at least one line, and lines of
+    /// only whitespace count as blank.
+    pub fn blocks(&self) -> impl Iterator<Item = Vec<Line<'_>>> {
+        let mut lines = self
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .peekable();
+        std::iter::from_fn(move || {
+            let mut block = vec![lines.next()?];
+            while let Some(line) =
+                lines.next_if(|line| line.number == block[block.len() - 1].number + 1)
+            {
+                block.push(line);
+            }
+            Some(block)
+        })
+    }
+
+    /// An error for input that stopped before something required showed up.
+    pub fn error_at_end(&self, expected: impl Display) -> ParseError {
+        ParseError {
+            file: self.source.clone(),
+            line: self.lines.last().map_or(1, |entry| entry.number + 1),
+            column: 1,
+            expected: expected.to_string(),
+            found: "end of input".to_string(),
+        }
+    }
+}
+
+/// One line of puzzle input. Dereferences to its text.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    source: &'a str,
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// The 1-based line number within the original input.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// An error at the `index`th character of the line.
+    pub fn error(&self, index: usize, expected: impl Display, found: impl Display) -> ParseError {
+        ParseError {
+            file: self.source.to_string(),
+            line: self.number,
+            column: self.indent + index + 1,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// An error at the start of `at`, which must be a slice of this line or a
+    /// suffix of it. The next token of `at` is reported as what was found.
+    pub fn error_at(&self, at: &str, expected: impl Display) -> ParseError {
+        let found = match at.split_whitespace().next() {
+            Some(token) => format!("'{}'", token),
+            None => "end of line".to_string(),
+        };
+        let offset = self.offset_of(at);
+        self.error(self.text[..offset].chars().count(), expected, found)
+    }
+
+    /// An error pointing at `token`, a slice of this line, as a whole.
+    pub fn error_on(&self, token: &str, expected: impl Display) -> ParseError {
+        let offset = self.offset_of(token);
+        let found = format!("'{}'", token);
+        self.error(self.text[..offset].chars().count(), expected, found)
+    }
+
+    /// Parses `token`, a slice of this line, reporting `expected` if it fails.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: impl Display) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error_on(token, expected))
+    }
+
+    /// Splits `text`, a slice of this line, on whitespace and parses every
+    /// piece as an integer. Unlike [`integers`](Line::integers), anything else
+    /// in `text` is an error.
+    pub fn split_integers<T: FromStr>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        text.split_whitespace()
+            .map(|token| self.parse(token, "a number"))
+            .collect()
+    }
+
+    /// Like [`split_integers`](Line::split_integers), but there must be
+    /// exactly `N` of them.
+    pub fn split_integer_array<T: FromStr, const N: usize>(
+        &self,
+        text: &str,
+    ) -> Result<[T; N], ParseError> {
+        self.split_integers(text)?
+            .try_into()
+            .map_err(|numbers: Vec<T>| {
+                let column = self.text[..self.offset_of(text)].chars().count();
+                let found = format!("{} numbers", numbers.len());
+                self.error(column, format!("{} numbers", N), found)
+            })
+    }
+
+    /// Picks every integer out of `text`, a slice of this line, skipping
+    /// whatever surrounds them. A `-` or `+` directly before the digits is
+    /// read as the sign, unless it follows another digit as in `3-4`.
+    pub fn integers<T: FromStr>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        let bytes = text.as_bytes();
+        let mut numbers = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            let signed = matches!(bytes[i], b'-' | b'+')
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+            if !signed && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            i += 1;
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            numbers.push(self.parse(&text[start..i], "a number")?);
+        }
+        Ok(numbers)
+    }
+
+    /// Like [`integers`](Line::integers), but there must be exactly `N` of
+    /// them.
+    pub fn integer_array<T: FromStr, const N: usize>(
+        &self,
+        text: &str,
+    ) -> Result<[T; N], ParseError> {
+        self.integers(text)?.try_into().map_err(|numbers: Vec<T>| {
+            let column = self.text[..self.offset_of(text)].chars().count();
+            self.error(
+                column,
+                format!("{} numbers", N),
+                format!("{} numbers", numbers.len()),
+            )
+        })
+    }
+
+    /// The integers in `text`, a slice of this line, after the first
+    /// occurrence of `label`: `"Time:"`, say, or `":"` to skip `"Card 3:"`.
+    /// It's an error if `label` isn't there.
+    pub fn integers_after<T: FromStr>(
+        &self,
+        text: &str,
+        label: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        let (_, rest) = text
+            .split_once(label)
+            .ok_or_else(|| self.error_at(text, format!("'{}'", label)))?;
+        self.integers(rest)
+    }
+
+    fn offset_of(&self, at: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let at_start = at.as_ptr() as usize;
+        if (start..=start + self.text.len()).contains(&at_start) {
+            at_start - start
+        } else {
+            self.text.len().saturating_sub(at.len())
+        }
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = PuzzleInput::new("test", "\n  abc\r\n1 2 3\n\n   \ndef  \n\n");
+        let expected = vec![(2, "abc"), (3, "1 2 3"), (6, "def")];
+        let actual = input
+            .lines()
+            .map(|line| (line.number(), line.as_str()))
+            .collect::<Vec<(usize, &str)>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = PuzzleInput::new("test", "\na\nb\n  \n\nc\n\nd\ne\n");
+        let expected = vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]];
+        let actual = input
+            .blocks()
+            .map(|block| block.iter().map(|line| line.as_str()).collect())
+            .collect::<Vec<Vec<&str>>>();
+        assert_eq!(expected, actual);
+        assert_eq!(0, PuzzleInput::new("test", "\n\n").blocks().count());
+    }
+
+    #[test]
+    fn test_raw_lines() {
+        let text = "\u{feff}  a  \r\n\r\n b\tc\n   \n\nd\n";
+        let input = PuzzleInput::with_mode("test", text, InputMode::Raw);
+        let expected = vec!["  a  ", "", " b\tc", "   ", "", "d"];
+        let actual = input
+            .lines()
+            .map(|line| line.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(expected, actual);
+        assert_eq!(3, input.blocks().count());
+
+        let trimmed = input.to_mode(InputMode::Trimmed);
+        let expected = vec![(1, "a"), (3, "b\tc"), (6, "d")];
+        let actual = trimmed
+            .lines()
+            .map(|line| (line.number(), line.as_str()))
+            .collect::<Vec<(usize, &str)>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_error_at() {
+        let input = PuzzleInput::new("test", "\n  Card 1: 4 x7 | 2");
+        let line = input.lines().next().unwrap();
+        let expected = "test:2:13: expected a number, found 'x7'";
+        let at = &line[line.find('x').unwrap()..];
+        assert_eq!(expected, line.error_at(at, "a number").to_string());
+
+        let expected = "test:2:19: expected '|', found end of line";
+        assert_eq!(expected, line.error_at("", "'|'").to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = PuzzleInput::new("test", "12 -4 z");
+        let line = input.lines().next().unwrap();
+        let tokens = line.split(' ').collect::<Vec<&str>>();
+        assert_eq!(Ok(12), line.parse::<u32>(tokens[0], "a number"));
+        assert_eq!(
+            "test:1:4: expected a positive number, found '-4'",
+            line.parse::<u32>(tokens[1], "a positive number")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let input = PuzzleInput::new(
+            "test",
+            "Time:  7 -15\nx=-3..+4, y=10-12\nCard 3: 41 48 | 83",
+        );
+        let lines = input.lines().collect::<Vec<Line>>();
+
+        assert_eq!(
+            Ok(vec![7, -15]),
+            lines[0].integers_after::<i64>(&lines[0], "Time:")
+        );
+        let expected = "test:1:1: expected 'Distance:', found 'Time:'";
+        let actual = lines[0].integers_after::<i64>(&lines[0], "Distance:");
+        assert_eq!(expected, actual.unwrap_err().to_string());
+        let expected = "test:1:10: expected a number, found '-15'";
+        let actual = lines[0].integers::<u64>(&lines[0]);
+        assert_eq!(expected, actual.unwrap_err().to_string());
+
+        assert_eq!(Ok(vec![-3, 4, 10, 12]), lines[1].integers::<i32>(&lines[1]));
+        assert_eq!(
+            Ok([-3, 4]),
+            lines[1].integer_array::<i32, 2>(&lines[1][..8])
+        );
+        let expected = "test:2:1: expected 3 numbers, found 4 numbers";
+        let actual = lines[1].integer_array::<i32, 3>(&lines[1]);
+        assert_eq!(expected, actual.unwrap_err().to_string());
+
+        let (winning, _) = lines[2].split_once('|').unwrap();
+        assert_eq!(
+            Ok(vec![41, 48]),
+            lines[2].integers_after::<u8>(winning, ":")
+        );
+        assert_eq!(Ok(vec![3, 41, 48]), lines[2].integers::<u8>(winning));
+    }
+
+    #[test]
+    fn test_split_integers() {
+        let input = PuzzleInput::new("test", "\n4 5 x\n1 2");
+        let lines = input.lines().collect::<Vec<Line>>();
+
+        assert_eq!(
+            Ok([4, 5]),
+            lines[0].split_integer_array::<u8, 2>(&lines[0][..3])
+        );
+        let expected = "test:2:5: expected a number, found 'x'";
+        let actual = lines[0].split_integers::<u8>(&lines[0]);
+        assert_eq!(expected, actual.unwrap_err().to_string());
+
+        let expected = "test:3:1: expected 3 numbers, found 2 numbers";
+        let actual = lines[1].split_integer_array::<i32, 3>(&lines[1]);
+        assert_eq!(expected, actual.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let input = PuzzleInput::new("test", "abc\n\ndef\n");
+        let expected = "test:4:1: expected a blank line, found end of input";
+        assert_eq!(expected, input.error_at_end("a blank line").to_string());
+    }
+}
@@ -0,0 +1,18 @@
+mod answer;
+mod error;
+pub mod geometry;
+mod grid;
+mod input;
+pub mod math;
+mod memo;
+pub mod parser;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{ParseError, Unimplemented};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use input::{InputMode, Line, PuzzleInput};
+pub use memo::{Memo, Stats};
+pub use solution::Solution;
@@ -0,0 +1,189 @@
+//! Number theory for the puzzles that hide a cycle or a system of
+//! congruences. Everything is generic over the signed integer types, so the
+//! same code can be rerun in `i128` when `i64` overflows; the checked
+//! functions return `None` rather than overflowing.
+
+use std::ops::{Div, Mul, Neg, Rem, Sub};
+
+/// A signed primitive integer.
+pub trait Integer:
+    Copy
+    + Ord
+    + Div<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + Rem<Output = Self>
+    + Sub<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn rem_euclid(self, modulus: Self) -> Self;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$t>::rem_euclid(self, modulus)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize);
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative, or `None` if it overflows.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).abs().checked_mul(b.abs())
+}
+
+/// The least common multiple, never negative.
+///
+/// Panics if the result overflows; see [`checked_lcm`].
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("least common multiple overflowed")
+}
+
+/// The greatest common divisor of all of `numbers`, or 0 if there are none.
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all of `numbers`, 1 if there are none, or
+/// `None` if it overflows.
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, checked_lcm)
+}
+
+/// Returns `(g, x, y)` where `g` is `gcd(a, b)` and `a * x + b * y == g`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == T::ONE).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` in `congruences`,
+/// returning the smallest non-negative `x` and the modulus it repeats with,
+/// the lcm of all the `m`. The moduli needn't be coprime. `None` means the
+/// congruences contradict each other or the arithmetic overflowed.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(r1, m1), (r2, m2)| {
+            let r2 = r2.rem_euclid(m2);
+            let g = gcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != T::ZERO {
+                return None;
+            }
+
+            // x = r1 + m1 * k, where m1 * k ≡ r2 - r1 (mod m2).
+            let step = m2 / g;
+            let inverse = mod_inverse(m1 / g, step)?;
+            let k = (diff / g)
+                .rem_euclid(step)
+                .checked_mul(inverse)?
+                .rem_euclid(step);
+            let modulus = (m1 / g).checked_mul(m2)?;
+            let x = m1.checked_mul(k)?.checked_add(r1)?.rem_euclid(modulus);
+            Some((x, modulus))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(36, lcm(12i64, -18));
+        assert_eq!(0, gcd_all(Vec::<i64>::new()));
+        assert_eq!(4, gcd_all([8, 12, 20]));
+        assert_eq!(Some(60), lcm_all([4i64, 6, 10, 15]));
+        assert_eq!(Some(1), lcm_all(Vec::<i64>::new()));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let primes = [1_000_000_007i64, 998_244_353, 1_000_000_009];
+        assert_eq!(None, lcm_all(primes));
+
+        let expected = 1_000_000_007i128 * 998_244_353 * 1_000_000_009;
+        assert_eq!(Some(expected), lcm_all(primes.map(i128::from)));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(Some(4), mod_inverse(3i64, 11));
+        assert_eq!(Some(7), mod_inverse(-3i64, 11));
+        assert_eq!(None, mod_inverse(6i64, 9));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt([(2i64, 3), (3, 5), (2, 7)]));
+        // 6 and 4 share a factor of 2, so their remainders must agree mod 2.
+        assert_eq!(Some((9, 12)), crt([(3i64, 6), (1, 4)]));
+        assert_eq!(None, crt([(3i64, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt(Vec::<(i64, i64)>::new()));
+    }
+}
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Remembers the results of a recursive function. The function is handed a
+/// `recurse` callback for its subproblems, so the cache never has to be
+/// passed around by hand. Keys are best kept small, such as indexes into
+/// input the function borrows, rather than copies of the remaining input.
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: Stats,
+}
+
+/// How well a [`Memo`] has been doing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            limit: None,
+            stats: Stats::default(),
+        }
+    }
+
+    /// A memo that stops remembering new results once it holds `limit`.
+    pub fn with_limit(limit: usize) -> Self {
+        Memo {
+            limit: Some(limit),
+            ..Memo::new()
+        }
+    }
+
+    /// The result of `f` at `key`, computed at most once while there's room
+    /// to keep it.
+    pub fn get<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+
+        let value = f(&mut |key| self.get(key, f), key.clone());
+        if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+            self.cache.insert(key, value.clone());
+        }
+        value
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            entries: self.cache.len(),
+            ..self.stats
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lattice paths through an `x` by `y` grid, moving right or down.
+    fn paths(recurse: &mut dyn FnMut((u32, u32)) -> u64, (x, y): (u32, u32)) -> u64 {
+        if x == 0 || y == 0 {
+            1
+        } else {
+            recurse((x - 1, y)) + recurse((x, y - 1))
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let mut memo = Memo::new();
+        assert_eq!(184756, memo.get((10, 10), &paths));
+
+        let expected = Stats {
+            hits: 81,
+            misses: 120,
+            entries: 120,
+        };
+        assert_eq!(expected, memo.stats());
+
+        assert_eq!(184756, memo.get((10, 10), &paths));
+        assert_eq!(82, memo.stats().hits);
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut memo = Memo::with_limit(10);
+        assert_eq!(924, memo.get((6, 6), &paths));
+        assert_eq!(10, memo.stats().entries);
+        assert!(memo.stats().misses > 48);
+    }
+}
@@ -0,0 +1,216 @@
+//! Small parser combinators for line-based puzzle input. Parsers borrow from
+//! the text they read rather than copying it, and a failure remembers where it
+//! happened so it can be reported against the original line.
+
+use std::str::FromStr;
+
+use crate::{Line, ParseError};
+
+/// Where a parser gave up and what it wanted to see there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The rest of the input from the point of failure.
+    pub at: &'a str,
+    pub expected: String,
+}
+
+/// The parsed value and the input left over, or the failure.
+pub type ParseResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> ParseResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+fn fail<T>(at: &str, expected: impl Into<String>) -> ParseResult<'_, T> {
+    Err(Failure {
+        at,
+        expected: expected.into(),
+    })
+}
+
+/// Runs `parser` over the whole line, reporting failures and anything left
+/// over as errors at the right column.
+pub fn parse_line<'a, T>(line: Line<'a>, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    match parser.parse(line.as_str()) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(line.error_at(rest, "end of line")),
+        Err(failure) => Err(line.error_at(failure.at, failure.expected)),
+    }
+}
+
+/// Matches `tag` exactly.
+pub fn literal<'a>(tag: &'a str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(tag) {
+        Some(rest) => Ok((&input[..tag.len()], rest)),
+        None => fail(input, format!("'{}'", tag)),
+    }
+}
+
+/// An optionally negative decimal integer.
+pub fn integer<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+        let (number, rest) = input.split_at(sign + digits);
+        match number.parse() {
+            Ok(value) if digits > 0 => Ok((value, rest)),
+            _ => fail(input, "a number"),
+        }
+    }
+}
+
+/// One or more whitespace characters.
+pub fn whitespace<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let rest = input.trim_start();
+        if rest.len() == input.len() {
+            return fail(input, "whitespace");
+        }
+        Ok((&input[..input.len() - rest.len()], rest))
+    }
+}
+
+/// The first of `parsers` to succeed. If they all fail, every alternative is
+/// listed as expected.
+pub fn alt<'a, T, P: Parser<'a, T>, const N: usize>(parsers: [P; N]) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let mut expected = vec![];
+        for parser in &parsers {
+            match parser.parse(input) {
+                Ok(parsed) => return Ok(parsed),
+                Err(failure) => expected.push(failure.expected),
+            }
+        }
+        let expected = match expected.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => expected.concat(),
+        };
+        fail(input, expected)
+    }
+}
+
+/// One or more `item`s with `separator` between them. Once a separator has
+/// matched, the item after it is required.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after)) = separator.parse(rest) {
+            let (next, after) = item.parse(after)?;
+            items.push(next);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// `first` then `second`, keeping both.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `first` then `second`, keeping only the second.
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// `first` then `second`, keeping only the first.
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// Transforms what `parser` produces.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Reports a failure of `parser` as `expected` rather than its own message.
+pub fn label<'a, T>(parser: impl Parser<'a, T>, expected: &'a str) -> impl Parser<'a, T> {
+    move |input: &'a str| parser.parse(input).or_else(|_| fail(input, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PuzzleInput;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(Ok(("ab", "c")), literal("ab").parse("abc"));
+        assert_eq!(Ok((-12, " x")), integer::<i64>().parse("-12 x"));
+        assert_eq!(Ok(("  ", "x")), whitespace().parse("  x"));
+
+        let expected = Failure {
+            at: "-x",
+            expected: "a number".into(),
+        };
+        assert_eq!(Err(expected), integer::<i64>().parse("-x"));
+        assert!(integer::<u8>().parse("300").is_err());
+    }
+
+    #[test]
+    fn test_combinators() {
+        let list = separated(integer::<u32>(), pair(literal(","), whitespace()));
+        assert_eq!(Ok((vec![1, 2, 3], ";")), list.parse("1, 2,  3;"));
+        assert!(list.parse("1, x").is_err());
+
+        let color = alt([literal("red"), literal("blue")]);
+        let expected = Failure {
+            at: "green",
+            expected: "'red' or 'blue'".into(),
+        };
+        assert_eq!(Err(expected), color.parse("green"));
+
+        let cube = pair(
+            terminated(integer::<u32>(), whitespace()),
+            label(color, "a color"),
+        );
+        assert_eq!(Ok(((4, "blue"), "")), cube.parse("4 blue"));
+        assert_eq!("a color", cube.parse("4 pink").unwrap_err().expected);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let input = PuzzleInput::new("p.txt", "Game 7: x");
+        let line = input.lines().next().unwrap();
+        let game = preceded(literal("Game "), integer::<u32>());
+
+        let actual = parse_line(line, terminated(game, literal(":")));
+        let expected = "p.txt:1:8: expected end of line, found 'x'";
+        assert_eq!(expected, actual.unwrap_err().to_string());
+
+        let actual = parse_line(line, preceded(literal("Game "), literal("8")));
+        let expected = "p.txt:1:6: expected '8', found '7:'";
+        assert_eq!(expected, actual.unwrap_err().to_string());
+    }
+}
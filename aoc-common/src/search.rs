@@ -0,0 +1,308 @@
+//! Graph searches over nodes of any type. The graph is never built up front:
+//! each search is handed a function listing a node's neighbours, so grid
+//! positions, named nodes and whole puzzle states can be searched alike.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Everything a breadth-first search reached.
+#[derive(Debug, Clone)]
+pub struct Bfs<N> {
+    /// The fewest steps from any start to each reached node.
+    pub distances: HashMap<N, usize>,
+    /// The node each node was first reached from. Starts have no parent.
+    pub parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Bfs<N> {
+    /// A shortest path from one of the starts to `target`, both included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(parent) = self.parents.get(&path[path.len() - 1]) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Visits every node reachable from `starts`, nearest first.
+pub fn bfs<N, I>(starts: impl IntoIterator<Item = N>, mut neighbours: impl FnMut(&N) -> I) -> Bfs<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node] + 1;
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    Bfs { distances, parents }
+}
+
+/// The cheapest path from `start` to a node satisfying `is_goal`, both
+/// included, and its cost. `neighbours` lists each node's neighbours along
+/// with the cost of moving to them.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], but heads towards the goal first. `heuristic` estimates
+/// the cost left from a node, and must never overestimate it or the path
+/// found may not be the cheapest.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    // Nodes are numbered as they're found so the queue needn't order them.
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    // The cheapest known cost of each node and the node it came from.
+    let mut best = vec![(0, usize::MAX)];
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if cost > best[id].0 {
+            continue;
+        }
+        if is_goal(&nodes[id]) {
+            let mut path = vec![nodes[id].clone()];
+            let mut parent = best[id].1;
+            while parent != usize::MAX {
+                path.push(nodes[parent].clone());
+                parent = best[parent].1;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step) in neighbours(&nodes[id]) {
+            let next_cost = cost + step;
+            let next_id = match ids.get(&next) {
+                Some(&next_id) if next_cost >= best[next_id].0 => continue,
+                Some(&next_id) => {
+                    best[next_id] = (next_cost, id);
+                    next_id
+                }
+                None => {
+                    ids.insert(next.clone(), nodes.len());
+                    nodes.push(next);
+                    best.push((next_cost, id));
+                    nodes.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_id]);
+            queue.push(Reverse((estimate, next_cost, next_id)));
+        }
+    }
+
+    None
+}
+
+/// Splits `nodes` into groups that can reach each other, ordered by where
+/// each group's first node appears. `neighbours` must be symmetric, as in an
+/// undirected graph, and may lead to nodes outside `nodes`.
+pub fn components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut groups = vec![];
+    for node in nodes {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut group = vec![node];
+        let mut i = 0;
+        while i < group.len() {
+            for next in neighbours(&group[i]) {
+                if seen.insert(next.clone()) {
+                    group.push(next);
+                }
+            }
+            i += 1;
+        }
+        groups.push(group);
+    }
+    groups
+}
+
+/// The shape of a sequence that keeps applying the same step to a state,
+/// which must eventually repeat itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N> {
+    /// Steps taken before the sequence first enters its cycle.
+    pub start: usize,
+    /// Steps taken to go once round the cycle.
+    pub length: usize,
+    /// Every state up to the end of the first time round, `start + length`
+    /// of them, beginning with the initial state.
+    pub states: Vec<N>,
+}
+
+impl<N> Cycle<N> {
+    /// The state after `steps` steps, however many that is.
+    pub fn nth(&self, steps: usize) -> &N {
+        if steps < self.states.len() {
+            &self.states[steps]
+        } else {
+            &self.states[self.start + (steps - self.start) % self.length]
+        }
+    }
+}
+
+/// Applies `next` to `start` until a state comes round again. Every state is
+/// remembered, so this suits cycles of up to a few million states.
+pub fn find_cycle<N>(start: N, mut next: impl FnMut(&N) -> N) -> Cycle<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = start;
+    loop {
+        if let Some(&first) = seen.get(&state) {
+            return Cycle {
+                start: first,
+                length: states.len() - first,
+                states,
+            };
+        }
+        seen.insert(state.clone(), states.len());
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    const MAZE: [&str; 5] = ["S..#.", ".#.#.", ".#...", ".####", "....E"];
+
+    fn open(point: Point) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(point.x), usize::try_from(point.y)) else {
+            return false;
+        };
+        let cell = MAZE.get(y).and_then(|row| row.as_bytes().get(x));
+        cell.is_some_and(|c| *c != b'#')
+    }
+
+    fn steps(point: &Point) -> impl Iterator<Item = Point> {
+        point.neighbours4().filter(|next| open(*next))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs([Point::ORIGIN], steps);
+        let exit = Point::new(4, 4);
+        assert_eq!(Some(&8), search.distances.get(&exit));
+        assert_eq!(None, search.distances.get(&Point::new(3, 0)));
+
+        let path = search.path_to(&exit).unwrap();
+        assert_eq!(9, path.len());
+        assert_eq!((Point::ORIGIN, exit), (path[0], path[8]));
+        assert!(path.windows(2).all(|pair| pair[0].manhattan(pair[1]) == 1));
+        assert_eq!(None, search.path_to(&Point::new(3, 0)));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Going through b looks cheap at first but costs more overall.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 4)]),
+            ('b', vec![('d', 7)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![]),
+            ('e', vec![('a', 1)]),
+        ]);
+        let neighbours = |node: &char| edges[node].clone();
+
+        let expected = Some((6, vec!['a', 'c', 'd']));
+        assert_eq!(expected, dijkstra('a', neighbours, |node| *node == 'd'));
+        assert_eq!(None, dijkstra('a', neighbours, |node| *node == 'e'));
+
+        let exit = Point::new(4, 4);
+        let found = astar(
+            Point::ORIGIN,
+            |point| steps(point).map(|next| (next, 1)),
+            |point| point.manhattan(exit) as u64,
+            |point| *point == exit,
+        );
+        let (cost, path) = found.unwrap();
+        assert_eq!(8, cost);
+        assert_eq!(Some(path), bfs([Point::ORIGIN], steps).path_to(&exit));
+    }
+
+    #[test]
+    fn test_components() {
+        let edges = [(1, 2), (2, 3), (4, 5), (7, 7)];
+        let neighbours = |node: &u32| {
+            let other = |&(a, b): &(u32, u32)| match *node {
+                n if n == a => Some(b),
+                n if n == b => Some(a),
+                _ => None,
+            };
+            edges.iter().filter_map(other).collect::<Vec<u32>>()
+        };
+
+        let expected = vec![vec![3, 2, 1], vec![4, 5], vec![6], vec![7]];
+        assert_eq!(expected, components([3, 4, 6, 2, 7, 5], neighbours));
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // Powers of 3 come back round to 1 after 20 steps mod 100.
+        let cycle = find_cycle(1u32, |n| n * 3 % 100);
+        assert_eq!((0, 20), (cycle.start, cycle.length));
+
+        // 0, 1, 2, then 3, 4, 5, 6 forever.
+        let cycle = find_cycle(0u32, |n| if *n < 6 { n + 1 } else { 3 });
+        assert_eq!((3, 4), (cycle.start, cycle.length));
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], cycle.states);
+        assert_eq!(&5, cycle.nth(5));
+        assert_eq!(&4, cycle.nth(100));
+    }
+}
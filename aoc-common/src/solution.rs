@@ -0,0 +1,24 @@
+use anyhow::Result;
+
+use crate::{Answer, InputMode, PuzzleInput, Unimplemented};
+
+/// A day's puzzle: the input is parsed once into `Input`, and both parts are
+/// computed from that shared model.
+pub trait Solution {
+    type Input;
+
+    /// How the input is split into lines before it reaches `parse`.
+    const INPUT_MODE: InputMode = InputMode::Trimmed;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input>;
+
+    /// Parts can be left out until they are solved, and runners report them
+    /// as unimplemented.
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        Err(Unimplemented.into())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(Unimplemented.into())
+    }
+}
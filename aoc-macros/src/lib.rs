@@ -0,0 +1,70 @@
+use std::{env, fs, path::Path};
+
+use aoc_samples::MANIFEST;
+use proc_macro::TokenStream;
+
+/// Generates one `#[test]` per `[[sample]]` in the calling crate's
+/// `samples.toml`. Each test parses the sample file with the given solution
+/// and checks that the part's answer equals `expected`.
+#[proc_macro]
+pub fn sample_tests(input: TokenStream) -> TokenStream {
+    let solution = input.to_string();
+    let dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let code = match expand(&solution, &Path::new(&dir).join(MANIFEST)) {
+        Ok(code) => code,
+        Err(message) => format!("compile_error!({:?});", message),
+    };
+    code.parse().unwrap()
+}
+
+fn expand(solution: &str, manifest: &Path) -> Result<String, String> {
+    let contents = fs::read_to_string(manifest)
+        .map_err(|e| format!("reading {}: {}", manifest.display(), e))?;
+    let samples =
+        aoc_samples::parse(&contents).map_err(|e| format!("{}: {}", manifest.display(), e))?;
+
+    // Rebuild the tests whenever the manifest changes, and name the solution
+    // even when there are no samples so its import never goes unused.
+    let mut code = format!(
+        "const _: &str = include_str!({:?});\n\
+         const _: ::core::marker::PhantomData<{}> = ::core::marker::PhantomData;\n",
+        manifest.display().to_string(),
+        solution
+    );
+    for sample in samples {
+        code.push_str(&format!(
+            "#[test]\n\
+             fn {name}() {{\n\
+                 let input = ::aoc_common::PuzzleInput::from_file({file:?}).unwrap()\
+                     .to_mode(<{solution} as ::aoc_common::Solution>::INPUT_MODE);\n\
+                 let input = <{solution} as ::aoc_common::Solution>::parse(&input).unwrap();\n\
+                 let actual = <{solution} as ::aoc_common::Solution>::part{part}(&input).unwrap();\n\
+                 let Ok(expected) = {expected:?}.parse::<::aoc_common::Answer>();\n\
+                 assert_eq!(expected, actual);\n\
+             }}\n",
+            name = sample.test_name(),
+            solution = solution,
+            file = sample.file,
+            part = sample.part,
+            expected = sample.expected,
+        ));
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_without_samples() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("template")
+            .join(MANIFEST);
+        let code = expand("Day20", &manifest).unwrap();
+        assert!(!code.contains("#[test]"));
+        assert!(code.contains("PhantomData<Day20>"));
+    }
+}
@@ -0,0 +1,207 @@
+//! The `samples.toml` manifest listing a day's worked examples. It's read by
+//! `aoc_macros::sample_tests!` to generate tests and rewritten by
+//! `aoc samples`, so both go through the same rules here.
+
+use std::collections::HashSet;
+
+/// Where a day lists its examples, next to its `Cargo.toml`.
+pub const MANIFEST: &str = "samples.toml";
+
+pub const HEADER: &str = "\
+# Worked examples from the puzzle description. Each entry becomes a test via
+# `aoc_macros::sample_tests!`, and `aoc samples` keeps this file up to date.
+";
+
+/// One `[[sample]]` entry: a part's expected answer for a sample file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// The test's name, when it isn't left to [`Sample::test_name`].
+    pub name: Option<String>,
+    pub file: String,
+    pub part: u32,
+    pub expected: String,
+}
+
+impl Sample {
+    /// The name given in the manifest, or `test_part2_sample3` for part 2 of
+    /// `sample3.txt`.
+    pub fn test_name(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+        let stem = self.file.strip_suffix(".txt").unwrap_or(&self.file);
+        let stem = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        format!("test_part{}_{}", self.part, stem)
+    }
+}
+
+/// Reads a manifest, checking that every part is 1 or 2 and that no two
+/// samples end up with the same test name.
+pub fn parse(contents: &str) -> Result<Vec<Sample>, String> {
+    let table = contents.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let entries = match table.get("sample") {
+        Some(toml::Value::Array(entries)) => entries.as_slice(),
+        Some(_) => return Err("`sample` must be an array of tables".into()),
+        None => &[],
+    };
+
+    let samples = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let which = format!("sample {}", i + 1);
+            let text = |key: &str| entry.get(key).and_then(|v| v.as_str()).map(String::from);
+
+            let file = text("file").ok_or_else(|| format!("{} has no `file`", which))?;
+            let part = match entry.get("part").and_then(|v| v.as_integer()) {
+                Some(part @ (1 | 2)) => part as u32,
+                _ => return Err(format!("{} needs a `part` of 1 or 2", which)),
+            };
+            let expected = match entry.get("expected") {
+                Some(toml::Value::String(s)) => s.clone(),
+                Some(toml::Value::Integer(n)) => n.to_string(),
+                _ => return Err(format!("{} needs an `expected` answer", which)),
+            };
+            Ok(Sample {
+                name: text("name"),
+                file,
+                part,
+                expected,
+            })
+        })
+        .collect::<Result<Vec<Sample>, String>>()?;
+    check(&samples)?;
+    Ok(samples)
+}
+
+/// Checks what [`parse`] would reject in `samples` before they are written.
+pub fn check(samples: &[Sample]) -> Result<(), String> {
+    let mut names = HashSet::new();
+    for (i, sample) in samples.iter().enumerate() {
+        if !(1..=2).contains(&sample.part) {
+            return Err(format!("sample {} needs a `part` of 1 or 2", i + 1));
+        }
+        let name = sample.test_name();
+        if !names.insert(name.clone()) {
+            return Err(format!(
+                "sample {} is named {}, which is already taken",
+                i + 1,
+                name
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Writes `samples` out as a manifest, starting with the usual header.
+pub fn render(samples: &[Sample]) -> String {
+    let mut out = HEADER.to_string();
+    for sample in samples {
+        out.push_str("\n[[sample]]\n");
+        if let Some(name) = &sample.name {
+            out.push_str(&format!("name = {:?}\n", name));
+        }
+        out.push_str(&format!(
+            "file = {:?}\npart = {}\n",
+            sample.file, sample.part
+        ));
+        // Numbers stay numbers so the manifest reads like the puzzle.
+        match sample.expected.parse::<i64>() {
+            Ok(n) if n.to_string() == sample.expected => {
+                out.push_str(&format!("expected = {}\n", n))
+            }
+            _ => out.push_str(&format!("expected = {:?}\n", sample.expected)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let manifest = r#"
+[[sample]]
+file = "sample.txt"
+part = 1
+expected = 4
+
+[[sample]]
+name = "test_squeezed_loop"
+file = "sample-4.txt"
+part = 2
+expected = "10"
+"#;
+        let expected = vec![
+            Sample {
+                name: None,
+                file: "sample.txt".into(),
+                part: 1,
+                expected: "4".into(),
+            },
+            Sample {
+                name: Some("test_squeezed_loop".into()),
+                file: "sample-4.txt".into(),
+                part: 2,
+                expected: "10".into(),
+            },
+        ];
+        assert_eq!(expected, parse(manifest).unwrap());
+        assert_eq!(Vec::<Sample>::new(), parse("").unwrap());
+        assert_eq!(Vec::<Sample>::new(), parse(HEADER).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let missing_part = "[[sample]]\nfile = \"sample.txt\"\nexpected = 1\n";
+        assert_eq!(
+            "sample 1 needs a `part` of 1 or 2",
+            parse(missing_part).unwrap_err()
+        );
+
+        let duplicate = "[[sample]]\nfile = \"s.txt\"\npart = 1\nexpected = 1\n\
+                         [[sample]]\nfile = \"s.txt\"\npart = 1\nexpected = 2\n";
+        assert_eq!(
+            "sample 2 is named test_part1_s, which is already taken",
+            parse(duplicate).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_render_round_trips() {
+        let samples = vec![
+            Sample {
+                name: Some("test_jokers".into()),
+                file: "sample.txt".into(),
+                part: 2,
+                expected: "5905".into(),
+            },
+            Sample {
+                name: None,
+                file: "sample2.txt".into(),
+                part: 1,
+                expected: "0xff".into(),
+            },
+        ];
+        let rendered = render(&samples);
+        assert!(rendered.contains("expected = 5905\n"));
+        assert!(rendered.contains("expected = \"0xff\"\n"));
+        assert_eq!(samples, parse(&rendered).unwrap());
+    }
+
+    #[test]
+    fn test_test_name() {
+        let sample = Sample {
+            name: None,
+            file: "sample-4.txt".into(),
+            part: 2,
+            expected: "1".into(),
+        };
+        assert_eq!("test_part2_sample_4", sample.test_name());
+    }
+}
@@ -0,0 +1,225 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{Answer, PuzzleInput};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    registry,
+    report::{self, Align},
+};
+
+/// Accepted answers, checked in at the workspace root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Known>,
+}
+
+/// The accepted answers for one day's input. The input path is relative to
+/// the workspace root.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Known {
+    pub day: u32,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Known {
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&contents).with_context(|| format!("parsing {}", path.display()))
+    }
+}
+
+/// One recorded answer compared against what the solver produces now.
+pub struct Check {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub expected: Answer,
+    pub actual: Result<Answer>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+}
+
+/// Re-solves every recorded answer, or only `day`'s, against its input.
+pub fn verify(root: &Path, answers: &Answers, day: Option<u32>) -> Result<Vec<Check>> {
+    let days = registry::days();
+    let mut checks = vec![];
+
+    for known in answers
+        .entries
+        .iter()
+        .filter(|k| day.is_none_or(|day| k.day == day))
+    {
+        let registered = days.iter().find(|d| d.day == known.day).ok_or_else(|| {
+            anyhow!(
+                "answers are recorded for day {}, which has no solution registered",
+                known.day
+            )
+        })?;
+        let parsed = PuzzleInput::from_file(root.join(&known.input))
+            .and_then(|input| (registered.parse)(&input));
+
+        for part in registry::PARTS {
+            let Some(expected) = known.answer(part) else {
+                continue;
+            };
+            let Ok(expected) = expected.parse::<Answer>();
+            let actual = match &parsed {
+                Ok(parsed) => parsed.solve(part),
+                Err(e) => Err(anyhow!("{:#}", e)),
+            };
+            checks.push(Check {
+                day: known.day,
+                part,
+                input: known.input.clone(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    if checks.is_empty() {
+        match day {
+            Some(d) => bail!("no answers recorded for day {}", d),
+            None => bail!("no answers recorded"),
+        }
+    }
+    Ok(checks)
+}
+
+pub fn table(checks: &[Check]) -> String {
+    let header = ["Day", "Part", "Input", "Expected", "Actual", "Status"]
+        .map(String::from)
+        .to_vec();
+    let mut cells = vec![header];
+    for check in checks {
+        let (actual, status) = match &check.actual {
+            Ok(actual) if check.passed() => (actual.to_string(), "ok"),
+            Ok(actual) => (actual.to_string(), "MISMATCH"),
+            Err(e) => (format!("error: {:#}", e), "ERROR"),
+        };
+        cells.push(vec![
+            check.day.to_string(),
+            check.part.to_string(),
+            check.input.clone(),
+            check.expected.to_string(),
+            actual,
+            status.to_string(),
+        ]);
+    }
+
+    report::aligned(
+        &cells,
+        &[
+            Align::Right,
+            Align::Right,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+            Align::Left,
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[[answer]]
+day = 1
+input = "a.txt"
+part1 = "12"
+part2 = "99"
+
+[[answer]]
+day = 1
+input = "b.txt"
+part1 = "38"
+"#;
+
+    fn fake_root() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join("a.txt"), "1abc2\n").unwrap();
+        fs::write(root.path().join("b.txt"), "pqr3stu8vwx\n").unwrap();
+        fs::write(root.path().join(ANSWERS_FILE), ANSWERS).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_load() {
+        let root = fake_root();
+        let answers = Answers::load(&root.path().join(ANSWERS_FILE)).unwrap();
+        let expected = Known {
+            day: 1,
+            input: "b.txt".into(),
+            part1: Some("38".into()),
+            part2: None,
+        };
+        assert_eq!(2, answers.entries.len());
+        assert_eq!(expected, answers.entries[1]);
+    }
+
+    #[test]
+    fn test_verify() {
+        let root = fake_root();
+        let answers = Answers::load(&root.path().join(ANSWERS_FILE)).unwrap();
+        let checks = verify(root.path(), &answers, None).unwrap();
+
+        let expected = vec![(1, "a.txt", true), (2, "a.txt", false), (1, "b.txt", true)];
+        let actual = checks
+            .iter()
+            .map(|c| (c.part, c.input.as_str(), c.passed()))
+            .collect::<Vec<(u32, &str, bool)>>();
+        assert_eq!(expected, actual);
+        assert!(table(&checks).contains("99        12      MISMATCH"));
+    }
+
+    #[test]
+    fn test_verify_missing_input() {
+        let root = fake_root();
+        fs::remove_file(root.path().join("b.txt")).unwrap();
+        let answers = Answers::load(&root.path().join(ANSWERS_FILE)).unwrap();
+        let checks = verify(root.path(), &answers, Some(1)).unwrap();
+
+        assert!(!checks[2].passed());
+        assert!(checks[2].actual.is_err());
+    }
+
+    #[test]
+    fn test_verify_nothing_recorded() {
+        let root = fake_root();
+        let answers = Answers::load(&root.path().join(ANSWERS_FILE)).unwrap();
+        assert!(verify(root.path(), &answers, Some(2)).is_err());
+    }
+
+    #[test]
+    fn test_repository_answers_load() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let answers = Answers::load(&root.join(ANSWERS_FILE)).unwrap();
+        for known in &answers.entries {
+            assert!(root.join(&known.input).is_file(), "{}", known.input);
+        }
+    }
+}
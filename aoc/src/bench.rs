@@ -0,0 +1,338 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use aoc_common::{PuzzleInput, Unimplemented};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    registry::Day,
+    report::{self, Align},
+};
+
+/// How long one phase of a day (parsing, or a part) took over every run.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub day: u32,
+    pub phase: String,
+    pub runs: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+/// A benchmark run as written by `--save` and read back by `--baseline`.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Results {
+    pub timings: Vec<Timing>,
+}
+
+impl Results {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("{} is not a saved benchmark", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn find(&self, day: u32, phase: &str) -> Option<&Timing> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.phase == phase)
+    }
+}
+
+/// A phase that wasn't timed, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skipped {
+    pub day: u32,
+    pub phase: String,
+    pub reason: String,
+}
+
+impl Skipped {
+    pub fn new(day: u32, phase: &str, reason: &str) -> Self {
+        Skipped {
+            day,
+            phase: phase.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Parses `input` and solves each of `parts` from it, `runs` times over,
+/// timing every phase separately. Parts that aren't written yet are skipped.
+pub fn measure(
+    day: &Day,
+    parts: &[u32],
+    input: &PuzzleInput,
+    runs: u32,
+) -> Result<(Vec<Timing>, Vec<Skipped>)> {
+    let mut parse = vec![];
+    let mut solve = vec![vec![]; parts.len()];
+    let mut unimplemented = vec![false; parts.len()];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = (day.parse)(input).with_context(|| format!("parsing day {}", day.day))?;
+        parse.push(start.elapsed());
+
+        for (i, part) in parts.iter().enumerate() {
+            if unimplemented[i] {
+                continue;
+            }
+            let start = Instant::now();
+            match parsed.solve(*part) {
+                Ok(_) => solve[i].push(start.elapsed()),
+                Err(e) if e.is::<Unimplemented>() => unimplemented[i] = true,
+                Err(e) => return Err(e.context(format!("solving day {} part {}", day.day, part))),
+            }
+        }
+    }
+
+    let mut timings = vec![summarize(day.day, "parse", parse)];
+    let mut skipped = vec![];
+    for ((part, samples), unimplemented) in parts.iter().zip(solve).zip(unimplemented) {
+        let phase = format!("part{}", part);
+        if unimplemented {
+            skipped.push(Skipped::new(day.day, &phase, "not implemented"));
+        } else {
+            timings.push(summarize(day.day, &phase, samples));
+        }
+    }
+    Ok((timings, skipped))
+}
+
+fn summarize(day: u32, phase: &str, mut samples: Vec<Duration>) -> Timing {
+    samples.sort();
+    let n = samples.len();
+    let median = if n.is_multiple_of(2) {
+        (samples[n / 2 - 1] + samples[n / 2]) / 2
+    } else {
+        samples[n / 2]
+    };
+
+    Timing {
+        day,
+        phase: phase.to_string(),
+        runs: n as u32,
+        min_ns: samples[0].as_nanos() as u64,
+        median_ns: median.as_nanos() as u64,
+        max_ns: samples[n - 1].as_nanos() as u64,
+    }
+}
+
+/// How much slower, in percent, `timing`'s median is than the baseline's.
+fn change(timing: &Timing, baseline: &Timing) -> f64 {
+    (timing.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0) * 100.0
+}
+
+/// The timings whose median is more than `threshold` percent slower than the
+/// same phase in `baseline`. Phases missing from the baseline are skipped.
+pub fn regressions<'a>(
+    timings: &'a [Timing],
+    baseline: &Results,
+    threshold: f64,
+) -> Vec<&'a Timing> {
+    timings
+        .iter()
+        .filter(|t| {
+            baseline
+                .find(t.day, &t.phase)
+                .is_some_and(|b| change(t, b) > threshold)
+        })
+        .collect()
+}
+
+/// Renders the timings as an aligned table, listing each skipped phase in
+/// its place with the reason instead of times.
+pub fn table(
+    timings: &[Timing],
+    skipped: &[Skipped],
+    baseline: Option<&Results>,
+    threshold: f64,
+) -> String {
+    let ns = |n: u64| report::format_duration(Duration::from_nanos(n));
+
+    let mut header = ["Day", "Phase", "Min", "Median", "Max"]
+        .map(String::from)
+        .to_vec();
+    let mut align = vec![
+        Align::Right,
+        Align::Left,
+        Align::Right,
+        Align::Right,
+        Align::Right,
+    ];
+    if baseline.is_some() {
+        header.extend(["Baseline", "Change"].map(String::from));
+        align.extend([Align::Right, Align::Left]);
+    }
+
+    let mut lines = vec![];
+    for timing in timings {
+        let mut line = vec![
+            timing.day.to_string(),
+            timing.phase.clone(),
+            ns(timing.min_ns),
+            ns(timing.median_ns),
+            ns(timing.max_ns),
+        ];
+        if let Some(baseline) = baseline {
+            match baseline.find(timing.day, &timing.phase) {
+                Some(before) => {
+                    let change = change(timing, before);
+                    line.push(ns(before.median_ns));
+                    line.push(if change > threshold {
+                        format!("{:+.1}% regressed", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    });
+                }
+                None => line.extend(["-", "new"].map(String::from)),
+            }
+        }
+        lines.push(line);
+    }
+    for skip in skipped {
+        lines.push(vec![
+            skip.day.to_string(),
+            skip.phase.clone(),
+            skip.reason.clone(),
+        ]);
+    }
+    // Days come in order, and within a day "input" < "parse" < "partN".
+    lines.sort_by_key(|line| (line[0].parse::<u32>().unwrap_or(0), line[1].clone()));
+
+    let mut cells = vec![header];
+    cells.extend(lines);
+
+    report::aligned(&cells, &align)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Parsed;
+    use aoc_common::Answer;
+
+    /// Solves part 1 and leaves part 2 unwritten.
+    struct HalfDone;
+
+    impl Parsed for HalfDone {
+        fn solve(&self, part: u32) -> Result<Answer> {
+            match part {
+                1 => Ok(Answer::from(1)),
+                _ => Err(Unimplemented.into()),
+            }
+        }
+    }
+
+    fn timing(day: u32, phase: &str, median_ns: u64) -> Timing {
+        Timing {
+            day,
+            phase: phase.to_string(),
+            runs: 3,
+            min_ns: median_ns / 2,
+            median_ns,
+            max_ns: median_ns * 2,
+        }
+    }
+
+    #[test]
+    fn test_summarize() {
+        let samples = [40, 10, 30, 20].map(Duration::from_nanos).to_vec();
+        let expected = Timing {
+            day: 4,
+            phase: "part1".to_string(),
+            runs: 4,
+            min_ns: 10,
+            median_ns: 25,
+            max_ns: 40,
+        };
+        assert_eq!(expected, summarize(4, "part1", samples));
+
+        let samples = [3, 1, 2].map(Duration::from_nanos).to_vec();
+        assert_eq!(2, summarize(4, "parse", samples).median_ns);
+    }
+
+    #[test]
+    fn test_measure_skips_unimplemented() {
+        let day = Day {
+            day: 13,
+            parse: |_| Ok(Box::new(HalfDone)),
+        };
+        let input = PuzzleInput::new("t.txt", "1\n");
+        let (timings, skipped) = measure(&day, &[1, 2], &input, 3).unwrap();
+
+        let phases = timings
+            .iter()
+            .map(|t| (t.phase.as_str(), t.runs))
+            .collect::<Vec<(&str, u32)>>();
+        assert_eq!(vec![("parse", 3), ("part1", 3)], phases);
+        assert_eq!(vec![Skipped::new(13, "part2", "not implemented")], skipped);
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = Results {
+            timings: vec![timing(1, "part1", 1000), timing(1, "part2", 1000)],
+        };
+        let timings = vec![
+            timing(1, "part1", 1050),
+            timing(1, "part2", 1200),
+            timing(2, "part1", 9000),
+        ];
+        let expected = vec![&timings[1]];
+        assert_eq!(expected, regressions(&timings, &baseline, 10.0));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bench.json");
+        let expected = Results {
+            timings: vec![timing(7, "parse", 1500)],
+        };
+        expected.save(&path).unwrap();
+        assert_eq!(expected, Results::load(&path).unwrap());
+    }
+
+    #[test]
+    fn test_table() {
+        let baseline = Results {
+            timings: vec![timing(1, "part1", 10_000)],
+        };
+        let timings = vec![timing(1, "part1", 12_000), timing(1, "part2", 4_000)];
+        let expected = "\
+Day  Phase    Min  Median     Max  Baseline  Change
+  1  part1  6.0µs  12.0µs  24.0µs    10.0µs  +20.0% regressed
+  1  part2  2.0µs   4.0µs   8.0µs         -  new
+";
+        assert_eq!(expected, table(&timings, &[], Some(&baseline), 10.0));
+    }
+
+    #[test]
+    fn test_table_lists_skipped() {
+        let timings = vec![timing(1, "parse", 2_000), timing(2, "parse", 2_000)];
+        let skipped = vec![
+            Skipped::new(1, "part1", "not implemented"),
+            Skipped::new(3, "input", "no input"),
+        ];
+        let expected = "\
+Day  Phase              Min  Median    Max
+  1  parse            1.0µs   2.0µs  4.0µs
+  1  part1  not implemented
+  2  parse            1.0µs   2.0µs  4.0µs
+  3  input         no input
+";
+        assert_eq!(expected, table(&timings, &skipped, None, 10.0));
+    }
+}
@@ -0,0 +1,172 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Holds the adventofcode.com session cookie; checked before the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const YEAR: u32 = 2023;
+
+const USER_AGENT: &str = concat!(
+    "github.com/mcasper/advent-of-code-2023 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Finds the session token in `$AOC_SESSION`, or else in the config file at
+/// `$XDG_CONFIG_HOME/aoc/session` (`~/.config/aoc/session` by default).
+pub fn session_token() -> Result<String> {
+    resolve_session(env::var(SESSION_VAR).ok(), config_path())
+}
+
+fn config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_home.join("aoc").join("session"))
+}
+
+fn resolve_session(var: Option<String>, config: Option<PathBuf>) -> Result<String> {
+    if let Some(token) = var.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()) {
+        return Ok(token);
+    }
+
+    if let Some(path) = &config {
+        if path.is_file() {
+            let token = fs::read_to_string(path)
+                .with_context(|| format!("reading {}", path.display()))?
+                .trim()
+                .to_string();
+            if token.is_empty() {
+                bail!("{} is empty", path.display());
+            }
+            return Ok(token);
+        }
+    }
+
+    match config {
+        Some(path) => bail!(
+            "no session token: set {} or write it to {}",
+            SESSION_VAR,
+            path.display()
+        ),
+        None => bail!("no session token: set {}", SESSION_VAR),
+    }
+}
+
+/// Talks to adventofcode.com, or a stand-in at `base_url`, as a logged-in user.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent,
+        }
+    }
+
+    fn url(&self, day: u32, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads `day`'s puzzle input.
+    pub fn input(&self, day: u32) -> Result<String> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| describe(e, day))?;
+        Ok(response.into_string()?)
+    }
+
+    /// Posts `answer` for `day`'s `part`, returning the response page.
+    pub fn submit(&self, day: u32, part: u32, answer: &str) -> Result<String> {
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| describe(e, day))?;
+        Ok(response.into_string()?)
+    }
+}
+
+fn describe(error: ureq::Error, day: u32) -> anyhow::Error {
+    match error {
+        ureq::Error::Status(404, _) => anyhow!("day {} isn't unlocked yet", day),
+        ureq::Error::Status(400, _) | ureq::Error::Status(401, _) => {
+            anyhow!("the session token was rejected, it may have expired")
+        }
+        ureq::Error::Status(code, response) => anyhow!(
+            "adventofcode.com answered {} {}",
+            code,
+            response.status_text()
+        ),
+        ureq::Error::Transport(transport) => anyhow!(transport),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    fn test_resolve_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("session");
+
+        assert_eq!(
+            "abc",
+            resolve_session(Some(" abc\n".into()), Some(config.clone())).unwrap()
+        );
+        assert!(resolve_session(None, Some(config.clone()))
+            .unwrap_err()
+            .to_string()
+            .contains(&config.display().to_string()));
+
+        fs::write(&config, "def\n").unwrap();
+        assert_eq!("def", resolve_session(None, Some(config.clone())).unwrap());
+        assert_eq!(
+            "def",
+            resolve_session(Some("".into()), Some(config)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_input() {
+        let server = testing::serve(vec![(200, "1abc2\n".into())]);
+        let client = Client::new(&server.url, "token".into());
+        assert_eq!("1abc2\n", client.input(5).unwrap());
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2023/day/5/input HTTP/1.1\r\n"));
+        let headers = requests[0].to_lowercase();
+        assert!(headers.contains("cookie: session=token\r\n"));
+        assert!(headers.contains("user-agent: github.com/mcasper/advent-of-code-2023"));
+    }
+
+    #[test]
+    fn test_input_not_unlocked() {
+        let server = testing::serve(vec![(404, "Not Found".into())]);
+        let client = Client::new(&server.url, "token".into());
+        let expected = "day 25 isn't unlocked yet";
+        assert_eq!(expected, client.input(25).unwrap_err().to_string());
+    }
+}
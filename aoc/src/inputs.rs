@@ -0,0 +1,230 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::PuzzleInput;
+use log::debug;
+
+/// Points at a directory laid out as `<dir>/2023/day05.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const YEAR: u32 = 2023;
+
+/// Where to read a day's input from when it was chosen on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(value.into())
+        }
+    }
+}
+
+/// Reads the input for `day`, from `source` if given and otherwise from the
+/// first of the usual locations that exists.
+pub fn load(root: &Path, day: u32, source: Option<&Source>) -> Result<PuzzleInput> {
+    match source {
+        Some(Source::Stdin) => {
+            let text = io::read_to_string(io::stdin()).context("reading stdin")?;
+            Ok(PuzzleInput::new("<stdin>", &text))
+        }
+        Some(Source::Path(path)) => PuzzleInput::from_file(path),
+        None => {
+            let path = find(root, input_dir().as_deref(), day)?;
+            debug!("reading day {} input from {}", day, path.display());
+            PuzzleInput::from_file(path)
+        }
+    }
+}
+
+pub fn input_dir() -> Option<PathBuf> {
+    std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from)
+}
+
+/// Where a downloaded input belongs: the input directory when one is set,
+/// otherwise the day's own directory.
+pub fn download_path(root: &Path, input_dir: Option<&Path>, day: u32) -> PathBuf {
+    candidates(root, input_dir, day).remove(0)
+}
+
+/// The places an input is looked for, most specific first.
+fn candidates(root: &Path, input_dir: Option<&Path>, day: u32) -> Vec<PathBuf> {
+    let mut candidates = vec![];
+    if let Some(dir) = input_dir {
+        candidates.push(
+            dir.join(YEAR.to_string())
+                .join(format!("day{:02}.txt", day)),
+        );
+    }
+    candidates.push(root.join(format!("day{}", day)).join("input.txt"));
+    candidates
+}
+
+/// The first of the usual locations holding `day`'s input. Empty files are
+/// skipped, since they're placeholders rather than a real input.
+pub fn find(root: &Path, input_dir: Option<&Path>, day: u32) -> Result<PathBuf> {
+    let candidates = candidates(root, input_dir, day);
+    let has_input = |path: &&PathBuf| fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0);
+    if let Some(found) = candidates.iter().find(has_input) {
+        return Ok(found.clone());
+    }
+
+    let tried = candidates
+        .iter()
+        .map(|path| format!("  {}", path.display()))
+        .collect::<Vec<String>>()
+        .join("\n");
+    bail!(
+        "no input found for day {}, looked in:\n{}\npass --input <path> or set {}",
+        day,
+        tried,
+        INPUT_DIR_VAR
+    )
+}
+
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Makes sure `day`'s input is on disk, calling `download` only if no copy
+/// exists yet in any of the usual locations. An empty file is overwritten.
+pub fn fetch(
+    root: &Path,
+    input_dir: Option<&Path>,
+    day: u32,
+    download: impl FnOnce(u32) -> Result<String>,
+) -> Result<Fetched> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {}", day);
+    }
+    if let Ok(cached) = find(root, input_dir, day) {
+        return Ok(Fetched::Cached(cached));
+    }
+
+    let path = download_path(root, input_dir, day);
+    let parent = path.parent().unwrap_or(root);
+    if input_dir.is_none() && !parent.is_dir() {
+        bail!(
+            "{} doesn't exist yet, run `aoc new {}` first or set {}",
+            parent.display(),
+            day,
+            INPUT_DIR_VAR
+        );
+    }
+
+    let text = download(day)?;
+    fs::create_dir_all(parent)?;
+    fs::write(&path, text).with_context(|| format!("writing {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Client, scaffold, testing};
+
+    #[test]
+    fn test_source_from_str() {
+        assert_eq!(Source::Stdin, Source::from("-"));
+        assert_eq!(Source::Path("in.txt".into()), Source::from("in.txt"));
+    }
+
+    #[test]
+    fn test_find_prefers_input_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let inputs = root.path().join("inputs");
+        fs::create_dir_all(inputs.join("2023")).unwrap();
+        fs::create_dir_all(root.path().join("day5")).unwrap();
+        fs::write(inputs.join("2023/day05.txt"), "1").unwrap();
+        fs::write(root.path().join("day5/input.txt"), "2").unwrap();
+
+        let expected = inputs.join("2023/day05.txt");
+        assert_eq!(expected, find(root.path(), Some(&inputs), 5).unwrap());
+
+        let expected = root.path().join("day5/input.txt");
+        assert_eq!(expected, find(root.path(), None, 5).unwrap());
+    }
+
+    #[test]
+    fn test_find_falls_back_to_day_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let inputs = root.path().join("inputs");
+        fs::create_dir_all(root.path().join("day12")).unwrap();
+        fs::write(root.path().join("day12/input.txt"), "1").unwrap();
+
+        let expected = root.path().join("day12/input.txt");
+        assert_eq!(expected, find(root.path(), Some(&inputs), 12).unwrap());
+    }
+
+    #[test]
+    fn test_find_reports_every_location() {
+        let root = tempfile::tempdir().unwrap();
+        let inputs = root.path().join("inputs");
+
+        let message = find(root.path(), Some(&inputs), 3).unwrap_err().to_string();
+        assert!(message.contains("no input found for day 3"));
+        assert!(message.contains(&inputs.join("2023/day03.txt").display().to_string()));
+        assert!(message.contains(&root.path().join("day3/input.txt").display().to_string()));
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let root = tempfile::tempdir().unwrap();
+        let inputs = root.path().join("inputs");
+        let server = testing::serve(vec![(200, "0 3 6\n".into())]);
+        let client = Client::new(&server.url, "token".into());
+
+        let expected = inputs.join("2023/day09.txt");
+        match fetch(root.path(), Some(&inputs), 9, |day| client.input(day)).unwrap() {
+            Fetched::Downloaded(path) => assert_eq!(expected, path),
+            Fetched::Cached(_) => panic!("nothing was cached yet"),
+        }
+        assert_eq!("0 3 6\n", fs::read_to_string(&expected).unwrap());
+
+        match fetch(root.path(), Some(&inputs), 9, |day| client.input(day)).unwrap() {
+            Fetched::Cached(path) => assert_eq!(expected, path),
+            Fetched::Downloaded(_) => panic!("the cached input was downloaded again"),
+        }
+        assert_eq!(1, server.requests().len());
+    }
+
+    #[test]
+    fn test_fetch_after_new_day() {
+        let root = testing::fake_root();
+        // An empty placeholder, as older templates used to leave behind.
+        fs::write(root.path().join("template/input.txt"), "").unwrap();
+        scaffold::new_day(root.path(), 13).unwrap();
+        assert!(find(root.path(), None, 13).is_err());
+
+        let server = testing::serve(vec![(200, "1 2 3\n".into())]);
+        let client = Client::new(&server.url, "token".into());
+        let expected = root.path().join("day13/input.txt");
+        match fetch(root.path(), None, 13, |day| client.input(day)).unwrap() {
+            Fetched::Downloaded(path) => assert_eq!(expected, path),
+            Fetched::Cached(_) => panic!("the empty placeholder counted as an input"),
+        }
+        assert_eq!("1 2 3\n", fs::read_to_string(&expected).unwrap());
+    }
+
+    #[test]
+    fn test_fetch_needs_day_dir() {
+        let root = tempfile::tempdir().unwrap();
+        let result = fetch(root.path(), None, 9, |_| Ok("0 3 6\n".into()));
+        assert!(result.is_err());
+        assert!(!root.path().join("day9").exists());
+
+        fs::create_dir_all(root.path().join("day9")).unwrap();
+        fetch(root.path(), None, 9, |_| Ok("0 3 6\n".into())).unwrap();
+        assert!(root.path().join("day9/input.txt").is_file());
+    }
+}
@@ -0,0 +1,425 @@
+mod answers;
+mod bench;
+mod client;
+mod inputs;
+mod pool;
+mod registry;
+mod report;
+mod samples;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod testing;
+
+use std::{
+    env,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::PuzzleInput;
+use clap::{ArgAction, Parser, Subcommand};
+use log::{info, warn, LevelFilter};
+
+use inputs::Source;
+use registry::{Day, Parsed};
+use report::Row;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+    /// Log what's going on to stderr; repeat for more detail
+    #[arg(long, short, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Solve a day, or every day, against its real input
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u32>,
+        part: Option<u32>,
+        /// Run every registered day and part
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        all: bool,
+        /// Read the input from this file, or from stdin if `-`, instead of
+        /// looking in $AOC_INPUT_DIR and the day's directory
+        #[arg(long, value_name = "PATH", conflicts_with = "all")]
+        input: Option<String>,
+        /// How many parts to solve at once [default: one per core]
+        #[arg(long, short, value_name = "N")]
+        jobs: Option<NonZeroUsize>,
+    },
+    /// Time parsing and each part over repeated runs
+    Bench {
+        day: Option<u32>,
+        part: Option<u32>,
+        /// How many times to run each phase
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Write the results to this file as JSON
+        #[arg(long, value_name = "PATH")]
+        save: Option<PathBuf>,
+        /// Compare against results written earlier with --save
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Fail if a median is more than this many percent slower than the
+        /// baseline
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Re-solve every recorded answer, or one day's, and report mismatches
+    Verify { day: Option<u32> },
+    /// Download a day's input, unless it is already on disk
+    Fetch { day: u32 },
+    /// Solve a part against the real input and submit the answer
+    Submit { day: u32, part: u32 },
+    /// Write the examples from a saved puzzle page as sample files and tests
+    Samples {
+        day: u32,
+        /// The puzzle description, saved from the browser
+        page: PathBuf,
+    },
+    /// Run a day's sample tests
+    Test { day: u32 },
+    /// Generate a new day from the template and register it
+    New { day: u32 },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    match cli.command {
+        Commands::Run {
+            day,
+            part,
+            input,
+            jobs,
+            ..
+        } => run(
+            day,
+            part,
+            input.as_deref().map(Source::from),
+            jobs.map_or_else(pool::default_jobs, NonZeroUsize::get),
+        ),
+        Commands::Bench {
+            day,
+            part,
+            runs,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let baseline = baseline
+                .map(|path| bench::Results::load(&path))
+                .transpose()?;
+            run_bench(day, part, runs, save, baseline, threshold)
+        }
+        Commands::Verify { day } => verify(day),
+        Commands::Fetch { day } => fetch(day),
+        Commands::Submit { day, part } => submit(day, part),
+        Commands::Samples { day, page } => write_samples(day, &page),
+        Commands::Test { day } => test(day),
+        Commands::New { day } => {
+            scaffold::new_day(workspace_root(), day)?;
+            println!("Created day{}, solve it with `aoc run {}`", day, day);
+            Ok(())
+        }
+    }
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn select(day: Option<u32>, part: Option<u32>) -> Result<Vec<(Day, Vec<u32>)>> {
+    if let Some(p) = part {
+        if !registry::PARTS.contains(&p) {
+            bail!("there is no part {}", p);
+        }
+    }
+    let parts = registry::PARTS
+        .into_iter()
+        .filter(|p| part.is_none_or(|part| *p == part))
+        .collect::<Vec<u32>>();
+
+    let selected = registry::days()
+        .into_iter()
+        .filter(|d| day.is_none_or(|day| d.day == day))
+        .map(|d| (d, parts.clone()))
+        .collect::<Vec<(Day, Vec<u32>)>>();
+
+    if selected.is_empty() {
+        match day {
+            Some(d) => bail!("no solution registered for day {}", d),
+            None => bail!("no solutions registered"),
+        }
+    }
+    Ok(selected)
+}
+
+/// Shows warnings by default, then info, debug and trace messages with each
+/// `-v`. `RUST_LOG` overrides the level.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .init();
+}
+
+/// Parses a day's input once for all of its parts, timing the parse.
+fn parse_day(day: &Day, input: &Result<PuzzleInput>) -> (Result<Box<dyn Parsed>>, Duration) {
+    let start = Instant::now();
+    let parsed = match input {
+        Ok(input) => (day.parse)(input),
+        Err(e) => Err(anyhow!("{:#}", e)),
+    };
+    (parsed, start.elapsed())
+}
+
+/// Solves one part from its day's parsed input. The row's time includes the
+/// shared parse time.
+fn solve_part(day: u32, part: u32, parsed: &Result<Box<dyn Parsed>>, parse_time: Duration) -> Row {
+    info!("solving day {} part {}", day, part);
+    let start = Instant::now();
+    let answer = match parsed {
+        Ok(parsed) => parsed.solve(part),
+        Err(e) => Err(anyhow!("{:#}", e)),
+    };
+    let elapsed = parse_time + start.elapsed();
+    info!(
+        "finished day {} part {} in {}",
+        day,
+        part,
+        report::format_duration(elapsed)
+    );
+    Row {
+        day,
+        part,
+        answer,
+        elapsed,
+    }
+}
+
+fn run(day: Option<u32>, part: Option<u32>, source: Option<Source>, jobs: usize) -> Result<()> {
+    let selected = select(day, part)?;
+    // Read every input up front: stdin can only be read once, and a day's
+    // parts share its input.
+    let loaded = selected
+        .iter()
+        .map(|(day, _)| inputs::load(workspace_root(), day.day, source.as_ref()))
+        .collect::<Vec<Result<PuzzleInput>>>();
+
+    let start = Instant::now();
+    // Each day is parsed once, then its parts are solved from that model.
+    let days = selected.iter().map(|(day, _)| day).zip(&loaded).collect();
+    let parsed = pool::map(days, jobs, |(day, input)| parse_day(day, input))
+        .into_iter()
+        .map(|parsed| {
+            parsed.unwrap_or_else(|panic| (Err(anyhow!("panicked: {}", panic)), Duration::ZERO))
+        })
+        .collect::<Vec<(Result<Box<dyn Parsed>>, Duration)>>();
+    let work = selected
+        .iter()
+        .zip(&parsed)
+        .flat_map(|((day, parts), (parsed, parse_time))| {
+            parts
+                .iter()
+                .map(move |part| (day.day, *part, parsed, *parse_time))
+        })
+        .collect::<Vec<(u32, u32, &Result<Box<dyn Parsed>>, Duration)>>();
+    let keys = work
+        .iter()
+        .map(|(day, part, ..)| (*day, *part))
+        .collect::<Vec<(u32, u32)>>();
+    // Fewer jobs run than were asked for when there are fewer parts.
+    let threads = pool::threads(jobs, work.len());
+    info!("solving {} parts, {} at a time", work.len(), threads);
+    let rows = pool::map(work, jobs, |(day, part, parsed, parse_time)| {
+        solve_part(day, part, parsed, parse_time)
+    })
+    .into_iter()
+    .zip(keys)
+    .map(|(row, (day, part))| {
+        row.unwrap_or_else(|panic| Row {
+            day,
+            part,
+            answer: Err(anyhow!("panicked: {}", panic)),
+            elapsed: Duration::ZERO,
+        })
+    })
+    .collect::<Vec<Row>>();
+    let wall = start.elapsed();
+
+    print!("{}", report::table(&rows));
+    print!("{}", report::summary(&rows, wall, threads));
+
+    let failures = rows.iter().filter(|r| r.failed()).count();
+    if failures > 0 {
+        bail!("{} of {} parts failed", failures, rows.len());
+    }
+    Ok(())
+}
+
+fn run_bench(
+    day: Option<u32>,
+    part: Option<u32>,
+    runs: u32,
+    save: Option<PathBuf>,
+    baseline: Option<bench::Results>,
+    threshold: f64,
+) -> Result<()> {
+    let mut results = bench::Results::default();
+    let mut skipped = vec![];
+    for (day, parts) in select(day, part)? {
+        let input = match inputs::load(workspace_root(), day.day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                warn!("{:#}", e);
+                skipped.push(bench::Skipped::new(day.day, "input", "no input"));
+                continue;
+            }
+        };
+        let (timings, unimplemented) = bench::measure(&day, &parts, &input, runs)?;
+        results.timings.extend(timings);
+        skipped.extend(unimplemented);
+    }
+
+    print!(
+        "{}",
+        bench::table(&results.timings, &skipped, baseline.as_ref(), threshold)
+    );
+
+    if let Some(path) = save {
+        results.save(&path)?;
+    }
+    if let Some(baseline) = &baseline {
+        let regressed = bench::regressions(&results.timings, baseline, threshold);
+        if !regressed.is_empty() {
+            bail!(
+                "{} phases are more than {}% slower than the baseline",
+                regressed.len(),
+                threshold
+            );
+        }
+    }
+    Ok(())
+}
+
+fn verify(day: Option<u32>) -> Result<()> {
+    let root = workspace_root();
+    let answers = answers::Answers::load(&root.join(answers::ANSWERS_FILE))?;
+    let checks = answers::verify(root, &answers, day)?;
+
+    print!("{}", answers::table(&checks));
+
+    let failures = checks.iter().filter(|c| !c.passed()).count();
+    if failures > 0 {
+        bail!("{} of {} answers did not match", failures, checks.len());
+    }
+    Ok(())
+}
+
+fn fetch(day: u32) -> Result<()> {
+    let download = |day| client::Client::new(client::BASE_URL, client::session_token()?).input(day);
+    match inputs::fetch(
+        workspace_root(),
+        inputs::input_dir().as_deref(),
+        day,
+        download,
+    )? {
+        inputs::Fetched::Cached(path) => println!("Already have {}", path.display()),
+        inputs::Fetched::Downloaded(path) => println!("Saved {}", path.display()),
+    }
+    Ok(())
+}
+
+fn submit(day: u32, part: u32) -> Result<()> {
+    let (day, _) = select(Some(day), Some(part))?.remove(0);
+    let input = inputs::load(workspace_root(), day.day, None)?;
+    let answer = (day.parse)(&input)?.solve(part)?.to_string();
+
+    let path = workspace_root().join(submit::ATTEMPTS_FILE);
+    let mut attempts = submit::Attempts::load(&path)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    attempts.check(day.day, part, &answer, now)?;
+
+    let client = client::Client::new(client::BASE_URL, client::session_token()?);
+    let response = submit::submit(&mut attempts, &client, day.day, part, &answer, now);
+    attempts.save(&path)?;
+    let response = response?;
+
+    let verdict = match response.verdict {
+        submit::Verdict::Correct => "is correct!",
+        submit::Verdict::TooHigh => "is too high",
+        submit::Verdict::TooLow => "is too low",
+        submit::Verdict::Wrong => "is wrong",
+        submit::Verdict::Wait => "wasn't checked, submitted too soon",
+        submit::Verdict::AlreadySolved => "wasn't checked, this part is already solved",
+    };
+    println!("{} {}", answer, verdict);
+    if let Some(wait) = response.wait {
+        println!("Wait {} seconds before submitting again", wait);
+    }
+    Ok(())
+}
+
+fn write_samples(day: u32, page: &Path) -> Result<()> {
+    let day_dir = workspace_root().join(format!("day{}", day));
+    if !day_dir.is_dir() {
+        bail!(
+            "{} doesn't exist yet, run `aoc new {}` first",
+            day_dir.display(),
+            day
+        );
+    }
+
+    let html =
+        std::fs::read_to_string(page).with_context(|| format!("reading {}", page.display()))?;
+    let cases = samples::write(&day_dir, &samples::extract(&html)?)?;
+
+    let manifest = day_dir.join(aoc_samples::MANIFEST);
+    let existing = if manifest.exists() {
+        std::fs::read_to_string(&manifest)
+            .with_context(|| format!("reading {}", manifest.display()))?
+    } else {
+        String::new()
+    };
+    std::fs::write(&manifest, samples::merge(&existing, &cases)?)
+        .with_context(|| format!("writing {}", manifest.display()))?;
+
+    for case in &cases {
+        println!(
+            "Part {}: {} should give {}",
+            case.part, case.file, case.answer
+        );
+    }
+    println!("Updated {}", manifest.display());
+    Ok(())
+}
+
+fn test(day: u32) -> Result<()> {
+    select(Some(day), None)?;
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = process::Command::new(cargo)
+        .args(["test", "-p", &format!("day{}", day)])
+        .current_dir(workspace_root())
+        .status()?;
+    if !status.success() {
+        bail!("day {} sample tests failed", day);
+    }
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use std::{
+    any::Any,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    thread,
+};
+
+/// One job per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// How many worker threads [`map`] runs for `items` items when asked for
+/// `jobs`: never more than there are items, and at least one.
+pub fn threads(jobs: usize, items: usize) -> usize {
+    jobs.clamp(1, items.max(1))
+}
+
+/// Applies `f` to every item on `jobs` worker threads, returning the results
+/// in the order of the items. An item whose call panics gets the panic's
+/// message instead, and the other items carry on.
+pub fn map<T, R, F>(items: Vec<T>, jobs: usize, f: F) -> Vec<Result<R, String>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(
+        (0..count)
+            .map(|_| None)
+            .collect::<Vec<Option<Result<R, String>>>>(),
+    );
+
+    thread::scope(|scope| {
+        for _ in 0..threads(jobs, count) {
+            scope.spawn(|| loop {
+                let Some((i, item)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every job ran"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashSet, time::Duration};
+
+    #[test]
+    fn test_map_keeps_order() {
+        let expected = (0..20)
+            .map(|n| Ok(n * n))
+            .collect::<Vec<Result<u64, String>>>();
+        let actual = map((0..20).collect(), 4, |n: u64| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_map_uses_several_threads() {
+        let threads = map((0..8).collect(), 4, |_: u32| {
+            thread::sleep(Duration::from_millis(10));
+            thread::current().id()
+        });
+        let distinct = threads
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<HashSet<_>>();
+        assert!(distinct.len() > 1);
+        assert_eq!(
+            Vec::<Result<u32, String>>::new(),
+            map(vec![], 4, |n: u32| n)
+        );
+    }
+
+    #[test]
+    fn test_threads() {
+        assert_eq!(4, threads(4, 24));
+        assert_eq!(2, threads(8, 2));
+        assert_eq!(1, threads(8, 0));
+        assert_eq!(1, threads(0, 5));
+    }
+
+    #[test]
+    fn test_map_survives_panics() {
+        let expected = vec![Ok(1), Err("no 2 allowed".to_string()), Ok(3), Ok(4)];
+        let actual = map(vec![1, 2, 3, 4], 2, |n: u32| {
+            if n == 2 {
+                panic!("no 2 allowed");
+            }
+            n
+        });
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{bail, Result};
+use aoc_common::{Answer, PuzzleInput, Solution};
+
+/// A day's parsed input, with the concrete `Solution` erased so every day can
+/// be driven the same way. It can be shared between threads so the parts can
+/// be solved in parallel.
+pub trait Parsed: Send + Sync {
+    fn solve(&self, part: u32) -> Result<Answer>;
+}
+
+struct Model<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Model<S>
+where
+    S::Input: Send + Sync,
+{
+    fn solve(&self, part: u32) -> Result<Answer> {
+        match part {
+            1 => S::part1(&self.0),
+            2 => S::part2(&self.0),
+            _ => bail!("there is no part {}", part),
+        }
+    }
+}
+
+/// Parses `input` for `S`, splitting it up again first if `S` wants another
+/// input mode.
+fn parse<S: Solution + 'static>(input: &PuzzleInput) -> Result<Box<dyn Parsed>>
+where
+    S::Input: Send + Sync,
+{
+    let model = if input.mode() == S::INPUT_MODE {
+        S::parse(input)?
+    } else {
+        S::parse(&input.to_mode(S::INPUT_MODE))?
+    };
+    Ok(Box::new(Model::<S>(model)))
+}
+
+pub const PARTS: [u32; 2] = [1, 2];
+
+pub struct Day {
+    pub day: u32,
+    pub parse: fn(&PuzzleInput) -> Result<Box<dyn Parsed>>,
+}
+
+macro_rules! day {
+    ($day:literal, $solution:ty) => {
+        Day {
+            day: $day,
+            parse: parse::<$solution>,
+        }
+    };
+}
+
+/// Every registered day, in order.
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(1, day1::Day1),
+        day!(2, day2::Day2),
+        day!(3, day3::Day3),
+        day!(4, day4::Day4),
+        day!(5, day5::Day5),
+        day!(6, day6::Day6),
+        day!(7, day7::Day7),
+        day!(8, day8::Day8),
+        day!(9, day9::Day9),
+        day!(10, day10::Day10),
+        day!(11, day11::Day11),
+        day!(12, day12::Day12),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered_and_unique() {
+        let keys = days().iter().map(|d| d.day).collect::<Vec<u32>>();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        let day = &days()[0];
+        let parsed = (day.parse)(&PuzzleInput::new("test", "1abc2")).unwrap();
+        assert_eq!(Answer::from(12i64), parsed.solve(1).unwrap());
+        assert!(parsed.solve(3).is_err());
+    }
+}
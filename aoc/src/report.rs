@@ -0,0 +1,202 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use aoc_common::{Answer, Unimplemented};
+
+/// The outcome of solving one part of a day against one input.
+pub struct Row {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+impl Row {
+    /// Whether the part hasn't been written yet, as opposed to failing.
+    pub fn unimplemented(&self) -> bool {
+        matches!(&self.answer, Err(e) if e.is::<Unimplemented>())
+    }
+
+    pub fn failed(&self) -> bool {
+        self.answer.is_err() && !self.unimplemented()
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Lays out `cells` in columns separated by two spaces, the first row being
+/// the header.
+pub fn aligned(cells: &[Vec<String>], align: &[Align]) -> String {
+    let mut widths = vec![0; align.len()];
+    for line in cells {
+        for (i, cell) in line.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for line in cells {
+        let rendered = line
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match align[i] {
+                Align::Left => format!("{:<w$}", cell, w = widths[i]),
+                Align::Right => format!("{:>w$}", cell, w = widths[i]),
+            })
+            .collect::<Vec<String>>()
+            .join("  ");
+        out.push_str(rendered.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders the rows as an aligned table, one part per line.
+pub fn table(rows: &[Row]) -> String {
+    let header = ["Day", "Part", "Answer", "Time"].map(String::from).to_vec();
+    let mut cells = vec![header];
+    for row in rows {
+        let answer = match &row.answer {
+            Ok(answer) => answer.to_string(),
+            Err(_) if row.unimplemented() => "not implemented".to_string(),
+            Err(e) => format!("error: {:#}", e),
+        };
+        cells.push(vec![
+            row.day.to_string(),
+            row.part.to_string(),
+            answer,
+            format_duration(row.elapsed),
+        ]);
+    }
+
+    aligned(
+        &cells,
+        &[Align::Right, Align::Right, Align::Left, Align::Right],
+    )
+}
+
+/// Counts the outcomes and times of a run that took `wall` in all on `jobs`
+/// worker threads, the number that actually ran rather than the number asked
+/// for, and names the parts that failed or are missing.
+pub fn summary(rows: &[Row], wall: Duration, jobs: usize) -> String {
+    let failed = parts(rows, Row::failed);
+    let unimplemented = parts(rows, Row::unimplemented);
+    let solved = rows.len() - failed.len() - unimplemented.len();
+    let total = rows.iter().map(|row| row.elapsed).sum();
+
+    let mut out = format!(
+        "\n{} solved, {} failed, {} not implemented\n",
+        solved,
+        failed.len(),
+        unimplemented.len()
+    );
+    out.push_str(&format!(
+        "Total {} across {} job{}, {} wall clock\n",
+        format_duration(total),
+        jobs,
+        if jobs == 1 { "" } else { "s" },
+        format_duration(wall)
+    ));
+    if !failed.is_empty() {
+        out.push_str(&format!("Failed: {}\n", failed.join(", ")));
+    }
+    if !unimplemented.is_empty() {
+        out.push_str(&format!("Not implemented: {}\n", unimplemented.join(", ")));
+    }
+    out
+}
+
+fn parts(rows: &[Row], keep: fn(&Row) -> bool) -> Vec<String> {
+    rows.iter()
+        .filter(|row| keep(row))
+        .map(|row| format!("day {} part {}", row.day, row.part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("12.0µs", format_duration(Duration::from_micros(12)));
+        assert_eq!("1.50ms", format_duration(Duration::from_micros(1500)));
+        assert_eq!("2.000s", format_duration(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = vec![
+            Row {
+                day: 7,
+                part: 2,
+                answer: Ok(Answer::from(5905i64)),
+                elapsed: Duration::from_micros(12),
+            },
+            Row {
+                day: 10,
+                part: 1,
+                answer: Err(anyhow!("no input")),
+                elapsed: Duration::from_millis(3),
+            },
+        ];
+        let expected = "\
+Day  Part  Answer             Time
+  7     2  5905             12.0µs
+ 10     1  error: no input  3.00ms
+";
+        assert_eq!(expected, table(&rows));
+    }
+
+    #[test]
+    fn test_summary() {
+        let rows = vec![
+            Row {
+                day: 1,
+                part: 1,
+                answer: Ok(Answer::from(142i64)),
+                elapsed: Duration::from_millis(2),
+            },
+            Row {
+                day: 1,
+                part: 2,
+                answer: Err(anyhow!("bad line")),
+                elapsed: Duration::from_millis(1),
+            },
+            Row {
+                day: 13,
+                part: 2,
+                answer: Err(Unimplemented.into()),
+                elapsed: Duration::from_micros(500),
+            },
+        ];
+        assert!(!rows[0].failed());
+        assert!(rows[1].failed());
+        assert!(rows[2].unimplemented() && !rows[2].failed());
+
+        let expected = "
+1 solved, 1 failed, 1 not implemented
+Total 3.50ms across 4 jobs, 2.00ms wall clock
+Failed: day 1 part 2
+Not implemented: day 13 part 2
+";
+        assert_eq!(expected, summary(&rows, Duration::from_millis(2), 4));
+        assert!(table(&rows).contains("  not implemented  "));
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_samples::Sample;
+
+/// A worked example from the puzzle description: the sample input a part is
+/// demonstrated on, and the answer it should produce.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u32,
+    pub sample: String,
+    pub answer: String,
+}
+
+/// An example written to disk, ready to become a test.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Case {
+    pub part: u32,
+    pub file: String,
+    pub answer: String,
+}
+
+/// Pulls one example per part out of a saved puzzle page. Each part's answer
+/// is the last `<code><em>` in its article, and its sample is the nearest
+/// `<pre><code>` block before that, looking back into part one when part two
+/// reuses the earlier example.
+pub fn extract(html: &str) -> Result<Vec<Example>> {
+    let mut examples = vec![];
+    let mut last_block: Option<String> = None;
+
+    for (i, article) in articles(html).into_iter().enumerate() {
+        let mut answer = None;
+        let mut block_for_answer = None;
+        let mut pos = 0;
+        loop {
+            let block = article[pos..].find("<pre><code>").map(|at| pos + at);
+            let emphasized = article[pos..].find("<code><em>").map(|at| pos + at);
+            match (block, emphasized) {
+                (Some(b), e) if e.is_none_or(|e| b < e) => {
+                    let start = b + "<pre><code>".len();
+                    let end = start + closing(&article[start..], "</code></pre>")?;
+                    last_block = Some(unescape(&article[start..end]));
+                    pos = end;
+                }
+                (_, Some(e)) => {
+                    let start = e + "<code><em>".len();
+                    let end = start + closing(&article[start..], "</em></code>")?;
+                    answer = Some(unescape(&article[start..end]));
+                    block_for_answer = last_block.clone();
+                    pos = end;
+                }
+                _ => break,
+            }
+        }
+
+        let part = i as u32 + 1;
+        match (answer, block_for_answer) {
+            (Some(answer), Some(sample)) => examples.push(Example {
+                part,
+                sample,
+                answer,
+            }),
+            (Some(_), None) => bail!("part {} has an answer but no example before it", part),
+            _ => (),
+        }
+    }
+
+    if examples.is_empty() {
+        bail!("no examples found, is this a saved puzzle page?");
+    }
+    Ok(examples)
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[start..start + end]);
+        rest = &rest[start + end..];
+    }
+    articles
+}
+
+fn closing(html: &str, tag: &str) -> Result<usize> {
+    html.find(tag)
+        .with_context(|| format!("{} is never closed", tag))
+}
+
+/// Drops inline tags such as `<em>` and decodes the entities the site uses.
+fn unescape(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Writes each example's sample into `day_dir`, reusing an existing
+/// `sample*.txt` with the same contents and otherwise taking the next free
+/// name. An empty `sample*.txt` counts as free.
+pub fn write(day_dir: &Path, examples: &[Example]) -> Result<Vec<Case>> {
+    let mut cases = vec![];
+    for example in examples {
+        let file = match existing_sample(day_dir, &example.sample)? {
+            Some(file) => file,
+            None => {
+                let file = next_free(day_dir);
+                let path = day_dir.join(&file);
+                fs::write(&path, &example.sample)
+                    .with_context(|| format!("writing {}", path.display()))?;
+                file
+            }
+        };
+        cases.push(Case {
+            part: example.part,
+            file,
+            answer: example.answer.clone(),
+        });
+    }
+    Ok(cases)
+}
+
+fn sample_name(n: usize) -> String {
+    if n == 1 {
+        "sample.txt".to_string()
+    } else {
+        format!("sample{}.txt", n)
+    }
+}
+
+fn existing_sample(day_dir: &Path, sample: &str) -> Result<Option<String>> {
+    for n in 1.. {
+        let path: PathBuf = day_dir.join(sample_name(n));
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        if contents.trim() == sample.trim() {
+            return Ok(Some(sample_name(n)));
+        }
+    }
+    unreachable!()
+}
+
+fn next_free(day_dir: &Path) -> String {
+    (1..)
+        .map(sample_name)
+        .find(|name| fs::metadata(day_dir.join(name)).map_or(true, |m| m.len() == 0))
+        .unwrap()
+}
+
+/// Folds the cases into an existing manifest: a case replaces the entry for
+/// the same file and part, keeping its name, and is appended otherwise.
+pub fn merge(manifest: &str, cases: &[Case]) -> Result<String> {
+    let mut samples =
+        aoc_samples::parse(manifest).map_err(|e| anyhow!("parsing the sample manifest: {}", e))?;
+    for case in cases {
+        let existing = samples
+            .iter_mut()
+            .find(|s| s.file == case.file && s.part == case.part);
+        match existing {
+            Some(sample) => sample.expected = case.answer.clone(),
+            None => samples.push(Sample {
+                name: None,
+                file: case.file.clone(),
+                part: case.part,
+                expected: case.answer.clone(),
+            }),
+        }
+    }
+    // Never write a manifest that `sample_tests!` would refuse to compile.
+    aoc_samples::check(&samples).map_err(|e| anyhow!("updating the sample manifest: {}", e))?;
+    Ok(aoc_samples::render(&samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_samples::HEADER;
+
+    const DAY7: &str = include_str!("../testdata/day7_puzzle.html");
+    const DAY7_SAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\n";
+
+    #[test]
+    fn test_extract() {
+        let expected = vec![
+            Example {
+                part: 1,
+                sample: DAY7_SAMPLE.into(),
+                answer: "6440".into(),
+            },
+            Example {
+                part: 2,
+                sample: DAY7_SAMPLE.into(),
+                answer: "5905".into(),
+            },
+        ];
+        assert_eq!(expected, extract(DAY7).unwrap());
+    }
+
+    #[test]
+    fn test_extract_reuses_part_one_example() {
+        let html = "<article class=\"day-desc\"><pre><code>a &lt;<em>b</em>&gt;\n</code></pre>\
+                    <p>Answer <code><em>1</em></code>.</p></article>\
+                    <article class=\"day-desc\"><p>Now <code><em>2</em></code>.</p></article>";
+        let expected = vec![
+            Example {
+                part: 1,
+                sample: "a <b>\n".into(),
+                answer: "1".into(),
+            },
+            Example {
+                part: 2,
+                sample: "a <b>\n".into(),
+                answer: "2".into(),
+            },
+        ];
+        assert_eq!(expected, extract(html).unwrap());
+    }
+
+    #[test]
+    fn test_extract_rejects_other_pages() {
+        assert!(extract("<html><body>Not a puzzle</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("sample.txt"), "existing\n").unwrap();
+        fs::write(dir.path().join("sample2.txt"), DAY7_SAMPLE).unwrap();
+        let examples = vec![
+            Example {
+                part: 1,
+                sample: DAY7_SAMPLE.into(),
+                answer: "6440".into(),
+            },
+            Example {
+                part: 2,
+                sample: "new\n".into(),
+                answer: "1".into(),
+            },
+        ];
+
+        let expected = vec![
+            Case {
+                part: 1,
+                file: "sample2.txt".into(),
+                answer: "6440".into(),
+            },
+            Case {
+                part: 2,
+                file: "sample3.txt".into(),
+                answer: "1".into(),
+            },
+        ];
+        assert_eq!(expected, write(dir.path(), &examples).unwrap());
+        assert_eq!(
+            "existing\n",
+            fs::read_to_string(dir.path().join("sample.txt")).unwrap()
+        );
+        assert_eq!(
+            "new\n",
+            fs::read_to_string(dir.path().join("sample3.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_fills_empty_sample() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("sample.txt"), "").unwrap();
+        let examples = vec![Example {
+            part: 1,
+            sample: DAY7_SAMPLE.into(),
+            answer: "6440".into(),
+        }];
+
+        let expected = vec![Case {
+            part: 1,
+            file: "sample.txt".into(),
+            answer: "6440".into(),
+        }];
+        assert_eq!(expected, write(dir.path(), &examples).unwrap());
+        assert_eq!(
+            DAY7_SAMPLE,
+            fs::read_to_string(dir.path().join("sample.txt")).unwrap()
+        );
+        assert!(!dir.path().join("sample2.txt").exists());
+    }
+
+    #[test]
+    fn test_merge() {
+        let manifest = "\
+[[sample]]
+name = \"test_jokers\"
+file = \"sample.txt\"
+part = 2
+expected = 1
+";
+        let cases = vec![
+            Case {
+                part: 1,
+                file: "sample.txt".into(),
+                answer: "6440".into(),
+            },
+            Case {
+                part: 2,
+                file: "sample.txt".into(),
+                answer: "5905".into(),
+            },
+            Case {
+                part: 2,
+                file: "sample2.txt".into(),
+                answer: "0xff".into(),
+            },
+        ];
+        let expected = format!(
+            "{}
+[[sample]]
+name = \"test_jokers\"
+file = \"sample.txt\"
+part = 2
+expected = 5905
+
+[[sample]]
+file = \"sample.txt\"
+part = 1
+expected = 6440
+
+[[sample]]
+file = \"sample2.txt\"
+part = 2
+expected = \"0xff\"
+",
+            HEADER
+        );
+        assert_eq!(expected, merge(manifest, &cases).unwrap());
+        assert!(merge("sample = 1", &cases).is_err());
+
+        // An explicit name that a new entry's default name would clash with.
+        let manifest =
+            "[[sample]]\nname = \"test_part1_sample\"\nfile = \"s.txt\"\npart = 2\nexpected = 1\n";
+        let expected = "updating the sample manifest: \
+                        sample 2 is named test_part1_sample, which is already taken";
+        let actual = merge(manifest, &cases[..1]).unwrap_err();
+        assert_eq!(expected, actual.to_string());
+    }
+}
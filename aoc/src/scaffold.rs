@@ -0,0 +1,239 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+const PLACEHOLDER: &str = "REPLACE_ME_DAY_NUMBER";
+
+/// Generates `dayN/` from `template/` and registers it with the workspace and
+/// the runner. Nothing is written unless every step can be applied.
+pub fn new_day(root: &Path, day: u32) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {}", day);
+    }
+
+    let name = format!("day{}", day);
+    let day_dir = root.join(&name);
+    if day_dir.exists() {
+        bail!(
+            "{} already exists, refusing to overwrite it",
+            day_dir.display()
+        );
+    }
+
+    let mut files = vec![];
+    render_template(&root.join("template"), &day_dir, day, &mut files)?;
+
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("registry.rs");
+
+    files.push((
+        workspace_manifest.clone(),
+        add_workspace_member(&read(&workspace_manifest)?, &name)?,
+    ));
+    files.push((
+        runner_manifest.clone(),
+        add_runner_dependency(&read(&runner_manifest)?, &name)?,
+    ));
+    files.push((registry.clone(), add_day(&read(&registry)?, day)?));
+
+    for (path, contents) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+fn render_template(
+    from: &Path,
+    to: &Path,
+    day: u32,
+    files: &mut Vec<(PathBuf, String)>,
+) -> Result<()> {
+    let entries = fs::read_dir(from).with_context(|| format!("reading {}", from.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            render_template(&entry.path(), &target, day, files)?;
+        } else {
+            let contents = read(&entry.path())?.replace(PLACEHOLDER, &day.to_string());
+            files.push((target, contents));
+        }
+    }
+    Ok(())
+}
+
+fn add_workspace_member(manifest: &str, name: &str) -> Result<String> {
+    let quoted = format!("\"{}\"", name);
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| anyhow!("workspace manifest has no members list"))?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or_else(|| anyhow!("workspace members list is not closed"))?;
+
+    if manifest[start..end].contains(&quoted) {
+        bail!("{} is already a workspace member", name);
+    }
+
+    Ok(format!(
+        "{}    {},\n{}",
+        &manifest[..end],
+        quoted,
+        &manifest[end..]
+    ))
+}
+
+fn add_runner_dependency(manifest: &str, name: &str) -> Result<String> {
+    if manifest
+        .lines()
+        .any(|line| line.starts_with(&format!("{} =", name)))
+    {
+        bail!("the runner already depends on {}", name);
+    }
+
+    insert_after_last(
+        manifest,
+        |line| line.starts_with("day"),
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+    )
+    .ok_or_else(|| anyhow!("the runner manifest has no day dependencies"))
+}
+
+fn add_day(registry: &str, day: u32) -> Result<String> {
+    if registry.contains(&format!("day!({},", day)) {
+        bail!("day {} is already registered", day);
+    }
+
+    insert_after_last(
+        registry,
+        |line| line.trim_start().starts_with("day!("),
+        &format!("        day!({}, day{}::Day{}),", day, day, day),
+    )
+    .ok_or_else(|| anyhow!("the registry has no day entries"))
+}
+
+fn insert_after_last(contents: &str, matches: impl Fn(&str) -> bool, new: &str) -> Option<String> {
+    let lines = contents.lines().collect::<Vec<&str>>();
+    let index = lines.iter().rposition(|line| matches(line))?;
+
+    let mut out = lines[..=index].join("\n");
+    out.push('\n');
+    out.push_str(new);
+    for line in &lines[index + 1..] {
+        out.push('\n');
+        out.push_str(line);
+    }
+    out.push('\n');
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{fake_root, RUNNER, WORKSPACE};
+
+    #[test]
+    fn test_new_day() {
+        let root = fake_root();
+        let path = root.path();
+        new_day(path, 13).unwrap();
+
+        assert_eq!(
+            "name = \"day13\"\n",
+            fs::read_to_string(path.join("day13/Cargo.toml")).unwrap()
+        );
+        assert_eq!(
+            "pub mod part1;\n",
+            fs::read_to_string(path.join("day13/src/lib.rs")).unwrap()
+        );
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n    \"day13\",\n]\n",
+            fs::read_to_string(path.join("Cargo.toml")).unwrap()
+        );
+        assert_eq!(
+            format!("{}day13 = {{ path = \"../day13\" }}\n", RUNNER),
+            fs::read_to_string(path.join("aoc/Cargo.toml")).unwrap()
+        );
+        assert!(fs::read_to_string(path.join("aoc/src/registry.rs"))
+            .unwrap()
+            .contains("        day!(1, day1::Day1),\n        day!(13, day13::Day13),\n    ]\n"));
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = fake_root();
+        let path = root.path();
+        fs::create_dir_all(path.join("day1")).unwrap();
+        fs::write(path.join("day1/keep.txt"), "mine").unwrap();
+
+        assert!(new_day(path, 1).is_err());
+        assert_eq!(
+            "mine",
+            fs::read_to_string(path.join("day1/keep.txt")).unwrap()
+        );
+        assert_eq!(
+            WORKSPACE,
+            fs::read_to_string(path.join("Cargo.toml")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_day_leaves_tree_untouched_on_failure() {
+        let root = fake_root();
+        let path = root.path();
+        fs::write(path.join("aoc/src/registry.rs"), "vec![]\n").unwrap();
+
+        assert!(new_day(path, 14).is_err());
+        assert!(!path.join("day14").exists());
+        assert_eq!(
+            WORKSPACE,
+            fs::read_to_string(path.join("Cargo.toml")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_new_day_rejects_out_of_range() {
+        let root = fake_root();
+        assert!(new_day(root.path(), 0).is_err());
+        assert!(new_day(root.path(), 26).is_err());
+    }
+
+    #[test]
+    fn test_repository_template_renders() {
+        let template = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("template");
+        let mut files = vec![];
+        render_template(&template, Path::new("day20"), 20, &mut files).unwrap();
+
+        assert!(files
+            .iter()
+            .all(|(_, contents)| !contents.contains(PLACEHOLDER)));
+        assert!(!files.iter().any(|(path, _)| path.ends_with("input.txt")));
+        // With no samples yet, an import in the tests would go unused.
+        let (_, lib) = files
+            .iter()
+            .find(|(path, _)| path.ends_with("src/lib.rs"))
+            .unwrap();
+        let tests = &lib[lib.find("mod tests").unwrap()..];
+        assert!(tests.contains("sample_tests!(super::Day20)"));
+        assert!(!tests.contains("use "));
+        assert!(files
+            .iter()
+            .any(|(path, contents)| path.ends_with("Cargo.toml")
+                && contents.contains("name = \"day20\"")));
+    }
+}
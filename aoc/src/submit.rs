@@ -0,0 +1,371 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// Every submission made so far, kept at the workspace root and not checked in.
+pub const ATTEMPTS_FILE: &str = ".aoc/attempts.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted during a cooldown; nothing was checked.
+    Wait,
+    AlreadySolved,
+}
+
+/// What the response page said, and how many seconds to hold off before the
+/// next submission.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    pub wait: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time of the submission.
+    pub at: u64,
+    /// Unix time before which the site won't take another submission.
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Attempts {
+    pub attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    /// Loads the attempts at `path`, or none if nothing was submitted yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Attempts::default());
+        }
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn for_part(&self, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.day == day && a.part == part)
+    }
+
+    /// Refuses submissions that can't be right given earlier attempts, or
+    /// that would land inside the cooldown.
+    pub fn check(&self, day: u32, part: u32, answer: &str, now: u64) -> Result<()> {
+        let attempts = self.for_part(day, part).collect::<Vec<&Attempt>>();
+
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            bail!(
+                "day {} part {} was already solved with {}",
+                day,
+                part,
+                correct.answer
+            );
+        }
+        if let Some(wrong) = attempts
+            .iter()
+            .find(|a| a.answer == answer && is_wrong(a.verdict))
+        {
+            bail!("{} was already submitted and was wrong", wrong.answer);
+        }
+
+        let Ok(parsed) = answer.parse::<Answer>();
+        if let Some(value) = parsed.as_integer() {
+            let bound = |verdict| {
+                attempts
+                    .iter()
+                    .filter(move |a| a.verdict == verdict)
+                    .filter_map(|a| a.answer.parse::<Answer>().ok()?.as_integer())
+            };
+            if let Some(high) = bound(Verdict::TooHigh).min() {
+                if value >= high {
+                    bail!("{} can't be right, {} was already too high", value, high);
+                }
+            }
+            if let Some(low) = bound(Verdict::TooLow).max() {
+                if value <= low {
+                    bail!("{} can't be right, {} was already too low", value, low);
+                }
+            }
+        }
+
+        if let Some(retry_after) = self.attempts.iter().filter_map(|a| a.retry_after).max() {
+            if retry_after > now {
+                bail!(
+                    "the last answer was submitted too recently, wait {} more seconds",
+                    retry_after - now
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, day: u32, part: u32, answer: &str, response: &Response, now: u64) {
+        self.attempts.push(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: response.verdict,
+            at: now,
+            retry_after: response.wait.map(|wait| now + wait),
+        });
+    }
+}
+
+fn is_wrong(verdict: Verdict) -> bool {
+    matches!(verdict, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+}
+
+/// Checks `answer` against earlier attempts, submits it, and records what the
+/// site said.
+pub fn submit(
+    attempts: &mut Attempts,
+    client: &Client,
+    day: u32,
+    part: u32,
+    answer: &str,
+    now: u64,
+) -> Result<Response> {
+    attempts.check(day, part, answer, now)?;
+    let page = client.submit(day, part, answer)?;
+    let response = parse_response(&page)?;
+    attempts.record(day, part, answer, &response, now);
+    Ok(response)
+}
+
+/// Reads the verdict out of the page the site sends back for a submission.
+pub fn parse_response(page: &str) -> Result<Response> {
+    let text = article_text(page);
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if text.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        bail!("couldn't make sense of the response: {}", text);
+    };
+
+    Ok(Response {
+        verdict,
+        wait: wait_seconds(&text),
+    })
+}
+
+/// The text of the page's `<article>`, with tags dropped and whitespace
+/// collapsed.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Understands "Please wait one minute before trying again" after a wrong
+/// answer and "You have 2m 5s left to wait" during a cooldown.
+fn wait_seconds(text: &str) -> Option<u64> {
+    if let Some(rest) = text.split("Please wait ").nth(1) {
+        let mut words = rest.split(' ');
+        let count = match words.next()? {
+            "one" | "a" => 1,
+            n => n.parse::<u64>().ok()?,
+        };
+        let unit = words.next()?;
+        return if unit.starts_with("minute") {
+            Some(count * 60)
+        } else if unit.starts_with("second") {
+            Some(count)
+        } else {
+            None
+        };
+    }
+
+    let rest = text.split("You have ").nth(1)?;
+    let left = rest.split(" left to wait").next()?;
+    let mut seconds = 0;
+    for part in left.split(' ') {
+        let (n, unit) = part.split_at(part.len().checked_sub(1)?);
+        let n = n.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    const CORRECT: &str = include_str!("../testdata/submit_correct.html");
+    const TOO_HIGH: &str = include_str!("../testdata/submit_too_high.html");
+    const TOO_LOW: &str = include_str!("../testdata/submit_too_low.html");
+    const WRONG: &str = include_str!("../testdata/submit_wrong.html");
+    const WAIT: &str = include_str!("../testdata/submit_wait.html");
+    const ALREADY_SOLVED: &str = include_str!("../testdata/submit_already_solved.html");
+
+    fn response(verdict: Verdict, wait: Option<u64>) -> Response {
+        Response { verdict, wait }
+    }
+
+    fn attempt(answer: &str, verdict: Verdict, retry_after: Option<u64>) -> Attempt {
+        Attempt {
+            day: 7,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            at: 0,
+            retry_after,
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        use Verdict::*;
+        let cases = [
+            (CORRECT, response(Correct, None)),
+            (TOO_HIGH, response(TooHigh, Some(60))),
+            (TOO_LOW, response(TooLow, Some(300))),
+            (WRONG, response(Wrong, Some(60))),
+            (WAIT, response(Wait, Some(125))),
+            (ALREADY_SOLVED, response(AlreadySolved, None)),
+        ];
+        for (page, expected) in cases {
+            assert_eq!(expected, parse_response(page).unwrap());
+        }
+        assert!(parse_response("<html><article>Huh?</article></html>").is_err());
+    }
+
+    #[test]
+    fn test_check_refuses_known_wrong_answers() {
+        let attempts = Attempts {
+            attempts: vec![
+                attempt("500", Verdict::TooHigh, None),
+                attempt("100", Verdict::TooLow, None),
+                attempt("250", Verdict::Wrong, None),
+            ],
+        };
+        assert!(attempts.check(7, 1, "250", 0).is_err());
+        assert!(attempts.check(7, 1, "500", 0).is_err());
+        assert!(attempts.check(7, 1, "700", 0).is_err());
+        assert!(attempts.check(7, 1, "99", 0).is_err());
+        assert!(attempts.check(7, 1, "300", 0).is_ok());
+        assert!(attempts.check(7, 2, "700", 0).is_ok());
+    }
+
+    #[test]
+    fn test_check_refuses_solved_parts() {
+        let attempts = Attempts {
+            attempts: vec![attempt("6440", Verdict::Correct, None)],
+        };
+        assert!(attempts.check(7, 1, "6440", 0).is_err());
+        assert!(attempts.check(7, 1, "1", 0).is_err());
+    }
+
+    #[test]
+    fn test_check_respects_cooldown() {
+        let attempts = Attempts {
+            attempts: vec![attempt("5", Verdict::Wrong, Some(160))],
+        };
+        let expected = "the last answer was submitted too recently, wait 60 more seconds";
+        let actual = attempts.check(8, 2, "6", 100).unwrap_err();
+        assert_eq!(expected, actual.to_string());
+        assert!(attempts.check(8, 2, "6", 160).is_ok());
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = testing::serve(vec![(200, TOO_LOW.into()), (200, CORRECT.into())]);
+        let client = Client::new(&server.url, "token".into());
+        let mut attempts = Attempts::default();
+
+        let actual = submit(&mut attempts, &client, 7, 2, "100", 1000).unwrap();
+        assert_eq!(response(Verdict::TooLow, Some(300)), actual);
+
+        assert!(submit(&mut attempts, &client, 7, 2, "200", 1100).is_err());
+        let actual = submit(&mut attempts, &client, 7, 2, "200", 1300).unwrap();
+        assert_eq!(response(Verdict::Correct, None), actual);
+
+        let requests = server.requests();
+        assert_eq!(2, requests.len());
+        assert!(requests[0].starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=100"));
+
+        let expected = vec![
+            Attempt {
+                day: 7,
+                part: 2,
+                answer: "100".into(),
+                verdict: Verdict::TooLow,
+                at: 1000,
+                retry_after: Some(1300),
+            },
+            Attempt {
+                day: 7,
+                part: 2,
+                answer: "200".into(),
+                verdict: Verdict::Correct,
+                at: 1300,
+                retry_after: None,
+            },
+        ];
+        assert_eq!(expected, attempts.attempts);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ATTEMPTS_FILE);
+        assert_eq!(Attempts::default(), Attempts::load(&path).unwrap());
+
+        let expected = Attempts {
+            attempts: vec![attempt("5", Verdict::Wait, Some(160))],
+        };
+        expected.save(&path).unwrap();
+        assert_eq!(expected, Attempts::load(&path).unwrap());
+    }
+}
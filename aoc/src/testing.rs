@@ -0,0 +1,92 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A local HTTP server standing in for adventofcode.com. It answers one
+/// connection per canned response, in order, then stops listening.
+pub struct StandIn {
+    pub url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StandIn {
+    /// Every request received so far, head and body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+pub fn serve(responses: Vec<(u16, String)>) -> StandIn {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+
+    let seen = requests.clone();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let Ok((stream, _)) = listener.accept() else {
+                return;
+            };
+            let mut reader = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    break;
+                }
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8_lossy(&content));
+            seen.lock().unwrap().push(request);
+
+            let mut stream = reader.into_inner();
+            let response = format!(
+                "HTTP/1.1 {} Stand-In\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    StandIn { url, requests }
+}
+
+pub const WORKSPACE: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day1\",\n]\n";
+pub const RUNNER: &str = "[dependencies]\nanyhow.workspace = true\nday1 = { path = \"../day1\" }\n";
+const REGISTRY: &str =
+    "pub fn days() -> Vec<Day> {\n    vec![\n        day!(1, day1::Day1),\n    ]\n}\n";
+
+/// A throwaway repository with a template and just enough of the workspace
+/// and runner for `aoc new` to register a day in.
+pub fn fake_root() -> tempfile::TempDir {
+    let root = tempfile::tempdir().unwrap();
+    let path = root.path();
+    fs::create_dir_all(path.join("template/src")).unwrap();
+    fs::create_dir_all(path.join("aoc/src")).unwrap();
+    fs::write(
+        path.join("template/Cargo.toml"),
+        "name = \"dayREPLACE_ME_DAY_NUMBER\"\n",
+    )
+    .unwrap();
+    fs::write(path.join("template/src/lib.rs"), "pub mod part1;\n").unwrap();
+    fs::write(path.join("Cargo.toml"), WORKSPACE).unwrap();
+    fs::write(path.join("aoc/Cargo.toml"), RUNNER).unwrap();
+    fs::write(path.join("aoc/src/registry.rs"), REGISTRY).unwrap();
+    root
+}
@@ -0,0 +1,136 @@
+use anyhow::{bail, Result};
+use aoc_common::{Answer, PuzzleInput, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let mut lines = vec![];
+        for line in input.lines() {
+            if let Some((i, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !c.is_ascii_alphanumeric())
+            {
+                return Err(line
+                    .error(i, "a letter or digit", format!("'{}'", c))
+                    .into());
+            }
+            lines.push(line.to_string());
+        }
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut numbers = vec![];
+
+        for line in input {
+            let mut first_number = -1;
+            let mut last_number = -1;
+
+            let chars = line.split("");
+            for c in chars {
+                if let Ok(i) = c.parse::<i64>() {
+                    if first_number == -1 {
+                        first_number = i;
+                    }
+
+                    last_number = i;
+                }
+            }
+
+            if first_number == -1 || last_number == -1 {
+                bail!("failed to find first or last number in '{}'", line);
+            }
+
+            numbers.push((first_number * 10) + last_number)
+        }
+
+        Ok(numbers.iter().sum::<i64>().into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut numbers = vec![];
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let mut substrs = vec![];
+
+        for line in input {
+            let mut first_number = -1;
+            let mut last_number = -1;
+
+            let chars = line.split("");
+            for c in chars {
+                if let Ok(i) = c.parse::<i64>() {
+                    if first_number == -1 {
+                        first_number = i;
+                    }
+
+                    last_number = i;
+                    substrs.clear();
+                } else {
+                    let mut new_substrs: Vec<String> =
+                        substrs.iter().map(|s| format!("{}{}", s, c)).collect();
+                    new_substrs.push(c.to_owned());
+
+                    substrs.clear();
+                    for substr in new_substrs {
+                        let mut matched = false;
+
+                        for (i, word) in words.iter().enumerate() {
+                            if word.starts_with(&substr) {
+                                matched = true;
+                                if word == &substr {
+                                    let ii = i + 1;
+
+                                    if first_number == -1 {
+                                        first_number = ii as i64;
+                                    }
+
+                                    last_number = ii as i64;
+                                    matched = false;
+                                }
+                            }
+                        }
+
+                        if matched {
+                            substrs.push(substr);
+                        }
+                    }
+                }
+            }
+
+            log::debug!(
+                "Found {} and {} for line {}",
+                first_number,
+                last_number,
+                line
+            );
+
+            if first_number == -1 || last_number == -1 {
+                bail!("failed to find first or last number in '{}'", line);
+            }
+
+            numbers.push((first_number * 10) + last_number)
+        }
+
+        Ok(numbers.iter().sum::<i64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day1);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:4: expected a letter or digit, found '-'";
+        let input = PuzzleInput::new("bad.txt", "1abc2\nabc-2\n");
+        assert_eq!(expected, Day1::parse(&input).unwrap_err().to_string());
+    }
+}
@@ -1,3 +0,0 @@
-fn main() {
-    println!("Run part1 or part2, not me!");
-}
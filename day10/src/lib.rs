@@ -0,0 +1,178 @@
+use anyhow::{bail, Result};
+use aoc_common::{geometry, search, Answer, Direction, Grid, Point, PuzzleInput, Solution};
+
+pub struct Day10;
+
+fn pipe_from_char(value: char) -> Result<Option<Pipe>> {
+    if value == '.' {
+        Ok(None)
+    } else {
+        Ok(Some(value.try_into()?))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Pipe {
+    Starting,
+    Vertical,
+    Horizontal,
+    NorthToEast,
+    NorthToWest,
+    SouthToWest,
+    SouthToEast,
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        Ok(match value {
+            'S' => Pipe::Starting,
+            '|' => Pipe::Vertical,
+            '-' => Pipe::Horizontal,
+            'L' => Pipe::NorthToEast,
+            'J' => Pipe::NorthToWest,
+            '7' => Pipe::SouthToWest,
+            'F' => Pipe::SouthToEast,
+            _ => bail!("unrecognized pipe '{}'", value),
+        })
+    }
+}
+
+impl Pipe {
+    /// The directions this pipe leads out of its cell. The starting position
+    /// hides the pipe underneath it, so it could lead anywhere.
+    fn exits(&self) -> &'static [Direction] {
+        use Direction::*;
+        match self {
+            Pipe::Starting => &Direction::ALL,
+            Pipe::Vertical => &[Up, Down],
+            Pipe::Horizontal => &[Left, Right],
+            Pipe::NorthToEast => &[Up, Right],
+            Pipe::NorthToWest => &[Up, Left],
+            Pipe::SouthToWest => &[Down, Left],
+            Pipe::SouthToEast => &[Down, Right],
+        }
+    }
+
+    fn connects_to(&self, point: Point, other_point: Point) -> bool {
+        self.exits().iter().any(|d| point.step(*d) == other_point)
+    }
+}
+
+fn valid_next_piece(last_point: Point, last_pipe: &Pipe, pipe: &Pipe, point: Point) -> bool {
+    last_pipe.connects_to(last_point, point) && pipe.connects_to(point, last_point)
+}
+
+pub struct Maze {
+    map: Grid<Option<Pipe>>,
+    starting_pos: Point,
+}
+
+impl Maze {
+    /// The tiles whose pipes join up with the pipe at `point`.
+    fn connected(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let pipe = self.map.at(point).and_then(Option::as_ref);
+        point
+            .neighbours4()
+            .filter(move |next| match (pipe, self.map.at(*next)) {
+                (Some(pipe), Some(Some(next_pipe))) => {
+                    valid_next_piece(point, pipe, next_pipe, *next)
+                }
+                _ => false,
+            })
+    }
+
+    /// Follows the pipes out of the starting position until they come back
+    /// around to it, returning every tile on that loop in order.
+    fn main_loop(&self) -> Result<Vec<Point>> {
+        let search = search::bfs([self.starting_pos], |point| self.connected(*point));
+
+        // Loops on a grid have an even length, so the two ways round from the
+        // start meet at a tile reached from both of its ends at once.
+        let meeting = search
+            .distances
+            .iter()
+            .filter(|(_, distance)| **distance > 0)
+            .filter_map(|(point, distance)| {
+                let ends = self
+                    .connected(*point)
+                    .filter(|end| search.distances.get(end) == Some(&(distance - 1)))
+                    .collect::<Vec<Point>>();
+                (ends.len() == 2).then_some((*distance, *point, ends))
+            })
+            .min();
+        let Some((_, point, ends)) = meeting else {
+            bail!("no loop leads back to the starting position");
+        };
+
+        let mut main_loop = search.path_to(&ends[0]).unwrap();
+        main_loop.push(point);
+        let back = search.path_to(&ends[1]).unwrap();
+        main_loop.extend(back.into_iter().skip(1).rev());
+        Ok(main_loop)
+    }
+}
+
+impl Solution for Day10 {
+    type Input = Maze;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let map = Grid::parse(input, "a pipe or '.'", |c| pipe_from_char(c).ok())?;
+
+        let starts = map
+            .iter()
+            .filter(|(_, pipe)| *pipe == &Some(Pipe::Starting))
+            .map(|(position, _)| position)
+            .collect::<Vec<(usize, usize)>>();
+        let (x, y) = *starts
+            .first()
+            .ok_or_else(|| input.error_at_end("a starting position 'S'"))?;
+        if let Some(&(x, y)) = starts.get(1) {
+            let line = input.lines().nth(y).unwrap();
+            return Err(line.error(x, "a single 'S'", "a second 'S'").into());
+        }
+
+        let starting_pos = Point::from((x, y));
+        Ok(Maze { map, starting_pos })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let loop_size = input.main_loop()?.len() as i64;
+        Ok((loop_size / 2).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        // The tiles enclosed by the loop are the lattice points inside the
+        // polygon through its tiles.
+        let main_loop = input.main_loop()?;
+        Ok(geometry::interior_points(&main_loop).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day10);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:3: expected a pipe or '.', found 'x'";
+        let input = PuzzleInput::new("bad.txt", "S-7\n|.x\nL-J\n");
+        let actual = Day10::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:3:1: expected a starting position 'S', found end of input";
+        let input = PuzzleInput::new("bad.txt", "F-7\nL-J\n");
+        let actual = Day10::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let input = PuzzleInput::with_mode("t.txt", "S-7\n|.|\nL-J\n\n", Day10::INPUT_MODE);
+        let input = Day10::parse(&input).unwrap();
+        assert_eq!(Answer::from(4i64), Day10::part1(&input).unwrap());
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+use aoc_common::{Answer, Grid, Point, PuzzleInput, Solution};
+
+pub struct Day11;
+
+pub struct Image {
+    galaxies: Vec<Point>,
+    empty_rows: Vec<i64>,
+    empty_columns: Vec<i64>,
+}
+
+impl Image {
+    /// Sums the shortest distances between every pair of galaxies after each
+    /// empty row and column has been replaced by `factor` empty ones.
+    fn total_distance(&self, factor: i64) -> i64 {
+        let expand = |n: i64, empty: &[i64]| {
+            n + empty.iter().filter(|e| **e < n).count() as i64 * (factor - 1)
+        };
+        let expanded_galaxies = self
+            .galaxies
+            .iter()
+            .map(|g| {
+                Point::new(
+                    expand(g.x, &self.empty_columns),
+                    expand(g.y, &self.empty_rows),
+                )
+            })
+            .collect::<Vec<Point>>();
+
+        let mut result = 0;
+        for (i, g1) in expanded_galaxies.iter().enumerate() {
+            for g2 in expanded_galaxies.iter().skip(i + 1) {
+                result += g1.manhattan(*g2);
+            }
+        }
+
+        result
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Image;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let image = Grid::parse(input, "'#' or '.'", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let galaxies = image
+            .iter()
+            .filter(|(_, galaxy)| **galaxy)
+            .map(|(position, _)| Point::from(position))
+            .collect();
+        let empty = |lines: Vec<bool>| {
+            lines
+                .into_iter()
+                .enumerate()
+                .filter(|(_, has_galaxy)| !has_galaxy)
+                .map(|(i, _)| i as i64)
+                .collect()
+        };
+
+        Ok(Image {
+            galaxies,
+            empty_rows: empty(image.rows().map(|row| row.contains(&true)).collect()),
+            empty_columns: empty(
+                image
+                    .columns()
+                    .map(|mut column| column.any(|g| *g))
+                    .collect(),
+            ),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(input.total_distance(2).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(input.total_distance(1000000).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day11);
+
+    #[test]
+    fn test_total_distance() {
+        let input = Day11::parse(&PuzzleInput::from_file("sample.txt").unwrap()).unwrap();
+        assert_eq!(1030, input.total_distance(10));
+        assert_eq!(8410, input.total_distance(100));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:2: expected '#' or '.', found '*'";
+        let input = PuzzleInput::new("bad.txt", "#..\n.*.\n");
+        let actual = Day11::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let input = PuzzleInput::with_mode("t.txt", "#..\n..#\n\n", Day11::INPUT_MODE);
+        let input = Day11::parse(&input).unwrap();
+        assert_eq!(Answer::from(4i64), Day11::part1(&input).unwrap());
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use aoc_common::{Answer, Line, Memo, ParseError, PuzzleInput, Solution};
+
+pub struct Day12;
+
+#[derive(Clone, Debug)]
+pub struct Report {
+    springs: Vec<char>,
+    broken_segments: Vec<usize>,
+}
+
+impl TryFrom<Line<'_>> for Report {
+    type Error = anyhow::Error;
+
+    fn try_from(line: Line<'_>) -> Result<Self> {
+        let (springs, segments) = line
+            .split_once(' ')
+            .ok_or_else(|| line.error_at("", "broken segment sizes"))?;
+        if let Some((i, c)) = springs
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !".#?".contains(*c))
+        {
+            return Err(line.error(i, "'.', '#' or '?'", format!("'{}'", c)).into());
+        }
+
+        Ok(Report {
+            springs: springs.chars().collect(),
+            broken_segments: segments
+                .split(',')
+                .map(|s| line.parse(s, "a segment size"))
+                .collect::<Result<Vec<usize>, ParseError>>()?,
+        })
+    }
+}
+
+impl Report {
+    fn unfold(&self) -> Self {
+        let mut new_springs = self.springs.clone();
+        let mut new_segments = self.broken_segments.clone();
+        for _ in 0..4 {
+            new_springs.push('?');
+            new_springs.append(&mut self.springs.clone());
+            new_segments.append(&mut self.broken_segments.clone());
+        }
+        Report {
+            springs: new_springs,
+            broken_segments: new_segments,
+        }
+    }
+
+    /// Ways to fill in the unknown springs from `spring` onwards so that the
+    /// broken ones form the segments from `segment` onwards.
+    fn arrangements(
+        &self,
+        recurse: &mut dyn FnMut((usize, usize)) -> i64,
+        (spring, segment): (usize, usize),
+    ) -> i64 {
+        let cs = &self.springs[spring.min(self.springs.len())..];
+        let segments = &self.broken_segments[segment..];
+
+        let Some(&l) = segments.first() else {
+            return if cs.iter().all(|c| c != &'#') { 1 } else { 0 };
+        };
+        if cs.is_empty() || l > cs.len() {
+            return 0;
+        }
+
+        // Whether the next segment can start right here.
+        let fits = cs[0..l].iter().all(|c| c == &'#' || c == &'?') && cs.get(l) != Some(&'#');
+        let place_segment = |recurse: &mut dyn FnMut((usize, usize)) -> i64| {
+            if fits {
+                recurse((spring + l + 1, segment + 1))
+            } else {
+                0
+            }
+        };
+
+        match cs[0] {
+            '.' => recurse((spring + 1, segment)),
+            '#' => place_segment(recurse),
+            _ => place_segment(recurse) + recurse((spring + 1, segment)),
+        }
+    }
+}
+
+fn total_arrangements(reports: &[Report]) -> i64 {
+    reports
+        .iter()
+        .map(|report| {
+            let arrangements = |recurse: &mut dyn FnMut((usize, usize)) -> i64, state| {
+                report.arrangements(recurse, state)
+            };
+            Memo::new().get((0, 0), &arrangements)
+        })
+        .sum()
+}
+
+impl Solution for Day12 {
+    type Input = Vec<Report>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        input.lines().map(Report::try_from).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(total_arrangements(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let unfolded: Vec<Report> = input.iter().map(|report| report.unfold()).collect();
+        Ok(total_arrangements(&unfolded).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day12);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:1:11: expected a segment size, found 'x'";
+        let input = PuzzleInput::new("bad.txt", "???.### 1,x,3\n");
+        let actual = Day12::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:1:3: expected '.', '#' or '?', found '*'";
+        let input = PuzzleInput::new("bad.txt", "??*.### 1,1,3\n");
+        let actual = Day12::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use aoc_common::{
+    parser::{
+        alt, integer, label, literal, map, pair, parse_line, preceded, separated, terminated,
+        whitespace, Parser,
+    },
+    Answer, PuzzleInput, Solution,
+};
+
+pub struct Day2;
+
+#[derive(Debug)]
+pub struct Game {
+    id: i64,
+    turns: Vec<Turn>,
+}
+
+#[derive(Debug)]
+struct Turn {
+    blue: i64,
+    red: i64,
+    green: i64,
+}
+
+impl Game {
+    fn max_blue(&self) -> i64 {
+        self.turns.iter().map(|t| t.blue).max().unwrap()
+    }
+
+    fn max_red(&self) -> i64 {
+        self.turns.iter().map(|t| t.red).max().unwrap()
+    }
+
+    fn max_green(&self) -> i64 {
+        self.turns.iter().map(|t| t.green).max().unwrap()
+    }
+}
+
+/// `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green`
+fn game<'a>() -> impl Parser<'a, Game> {
+    let color = label(
+        alt([literal("blue"), literal("green"), literal("red")]),
+        "a color",
+    );
+    let cubes = pair(terminated(integer::<i64>(), whitespace()), color);
+    let turn = map(
+        separated(cubes, pair(literal(","), whitespace())),
+        |cubes| {
+            let mut turn = Turn {
+                blue: 0,
+                red: 0,
+                green: 0,
+            };
+            for (count, color) in cubes {
+                match color {
+                    "blue" => turn.blue += count,
+                    "green" => turn.green += count,
+                    _ => turn.red += count,
+                }
+            }
+            turn
+        },
+    );
+    let turns = separated(turn, pair(literal(";"), whitespace()));
+
+    map(
+        pair(
+            preceded(literal("Game "), integer::<i64>()),
+            preceded(pair(literal(":"), whitespace()), turns),
+        ),
+        |(id, turns)| Game { id, turns },
+    )
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| Ok(parse_line(line, game())?))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let max_blue = 14;
+        let max_red = 12;
+        let max_green = 13;
+
+        let mut total = 0;
+        for game in input {
+            let possible = game.max_blue() <= max_blue
+                && game.max_green() <= max_green
+                && game.max_red() <= max_red;
+            if possible {
+                total += game.id;
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut total = 0;
+        for game in input {
+            total += game.max_blue() * game.max_red() * game.max_green();
+        }
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseError;
+
+    aoc_macros::sample_tests!(Day2);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = ParseError {
+            file: "bad.txt".into(),
+            line: 1,
+            column: 19,
+            expected: "a color".into(),
+            found: "'purple;'".into(),
+        };
+        let input = PuzzleInput::new("bad.txt", "Game 1: 3 blue, 4 purple; 1 red");
+        let actual = Day2::parse(&input).unwrap_err().downcast::<ParseError>();
+        assert_eq!(expected, actual.unwrap());
+
+        let input = PuzzleInput::new("bad.txt", "Game 2:");
+        assert!(Day2::parse(&input).is_err());
+    }
+}
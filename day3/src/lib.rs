@@ -0,0 +1,131 @@
+use anyhow::Result;
+use aoc_common::{Answer, Grid, PuzzleInput, Solution};
+
+pub struct Day3;
+
+/// A run of digits on one row, covering columns `start..end`.
+struct Number {
+    v: i64,
+    y: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Number {
+    fn covers(&self, (x, y): (usize, usize)) -> bool {
+        y == self.y && (self.start..self.end).contains(&x)
+    }
+}
+
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+fn is_symbol(c: char) -> bool {
+    "!@#$%^&*()_-+=~`/?".contains(c)
+}
+
+impl Schematic {
+    /// Whether any cell of `number` touches a symbol, diagonals included.
+    fn is_part_number(&self, number: &Number) -> bool {
+        (number.start..number.end).any(|x| {
+            self.grid
+                .neighbours8(x, number.y)
+                .any(|p| is_symbol(self.grid[p]))
+        })
+    }
+}
+
+impl Solution for Day3 {
+    type Input = Schematic;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let grid = Grid::parse(input, "a digit, a symbol or '.'", |c| {
+            (c.is_ascii_digit() || is_symbol(c) || c == '.').then_some(c)
+        })?;
+
+        let mut numbers: Vec<Number> = vec![];
+        for (y, row) in grid.rows().enumerate() {
+            let mut num: Option<Number> = None;
+            for (x, ch) in row.iter().enumerate() {
+                match (ch.to_digit(10), num.as_mut()) {
+                    (Some(d), Some(n)) => {
+                        n.v = n.v * 10 + d as i64;
+                        n.end = x + 1;
+                    }
+                    (Some(d), None) => {
+                        num = Some(Number {
+                            v: d as i64,
+                            y,
+                            start: x,
+                            end: x + 1,
+                        })
+                    }
+                    (None, _) => numbers.extend(num.take()),
+                }
+            }
+            numbers.extend(num);
+        }
+
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let total = input
+            .numbers
+            .iter()
+            .filter(|number| input.is_part_number(number))
+            .map(|number| number.v)
+            .sum::<i64>();
+
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut total = 0;
+        for (position, symbol) in input.grid.iter() {
+            if *symbol != '*' {
+                continue;
+            }
+
+            let adjacent = input
+                .numbers
+                .iter()
+                .filter(|number| {
+                    input
+                        .grid
+                        .neighbours8(position.0, position.1)
+                        .any(|p| number.covers(p))
+                })
+                .collect::<Vec<&Number>>();
+
+            if adjacent.len() == 2 {
+                total += adjacent[0].v * adjacent[1].v;
+            }
+        }
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day3);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:3: expected a digit, a symbol or '.', found 'a'";
+        let input = PuzzleInput::new("bad.txt", "467..\n..a35\n");
+        let actual = Day3::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn test_trailing_blank_line() {
+        let input = PuzzleInput::with_mode("t.txt", "467..\n...*.\n..35.\n\n", Day3::INPUT_MODE);
+        let input = Day3::parse(&input).unwrap();
+        assert_eq!(Answer::from(502i64), Day3::part1(&input).unwrap());
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_common::{Answer, Line, PuzzleInput, Solution};
+
+pub struct Day4;
+
+pub struct Card {
+    winning: Vec<i64>,
+    numbers: Vec<i64>,
+}
+
+impl Card {
+    fn winners(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|n| self.winning.contains(n))
+            .count()
+    }
+}
+
+impl TryFrom<Line<'_>> for Card {
+    type Error = anyhow::Error;
+
+    fn try_from(line: Line<'_>) -> Result<Self> {
+        let (winning, numbers) = line
+            .split_once('|')
+            .ok_or_else(|| line.error_at("", "'|'"))?;
+
+        Ok(Card {
+            winning: line.integers_after(winning, ":")?,
+            numbers: line.integers(numbers)?,
+        })
+    }
+}
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        input.lines().map(Card::try_from).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut result = 0;
+
+        for card in input {
+            let mut score = 0;
+            for _ in 0..card.winners() {
+                if score == 0 {
+                    score = 1;
+                } else {
+                    score *= 2;
+                }
+            }
+
+            result += score;
+        }
+
+        Ok(result.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut scorecard_count: HashMap<usize, i64> = HashMap::new();
+
+        for (i, _) in input.iter().enumerate() {
+            scorecard_count.insert(i + 1, 1);
+        }
+
+        for (i, card) in input.iter().enumerate() {
+            let card_number = i + 1;
+            let this_card_count = *scorecard_count.get(&card_number).unwrap();
+
+            for i in 0..card.winners() {
+                if let Some(v) = scorecard_count.get_mut(&(card_number + i + 1)) {
+                    *v += this_card_count;
+                }
+            }
+        }
+
+        let mut result = 0;
+        for (_, v) in scorecard_count {
+            result += v;
+        }
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day4);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:1:1: expected ':', found 'Card'";
+        let input = PuzzleInput::new("bad.txt", "Card 1 41 48 | 83 86");
+        let actual = Day4::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:1:20: expected '|', found end of line";
+        let input = PuzzleInput::new("bad.txt", "Card 1: 41 48 83 86");
+        let actual = Day4::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashMap, ops::Range};
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{Answer, Line, ParseError, PuzzleInput, Solution};
+
+pub struct Day5;
+
+#[derive(Clone, Debug)]
+struct MapRange {
+    dest_start: i64,
+    source_start: i64,
+    size: i64,
+}
+
+#[derive(Clone, Debug)]
+struct Map {
+    from: String,
+    to: String,
+    ranges: Vec<MapRange>,
+}
+
+impl Map {
+    fn new(from: String, to: String) -> Self {
+        Self {
+            from,
+            to,
+            ranges: vec![],
+        }
+    }
+
+    fn add_range(&mut self, dest_start: i64, source_start: i64, range: i64) {
+        self.ranges.push(MapRange {
+            dest_start,
+            source_start,
+            size: range,
+        })
+    }
+
+    fn resolve(&self, n: i64) -> i64 {
+        for range in &self.ranges {
+            if n >= range.source_start && n < (range.source_start + range.size) {
+                let offset = n - range.source_start;
+                return range.dest_start + offset;
+            }
+        }
+
+        n
+    }
+
+    /// Resolves a whole range of numbers at once, splitting it wherever it
+    /// crosses the edge of one of the map's ranges.
+    fn resolve_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let mut unmapped = vec![range];
+        let mut mapped = vec![];
+
+        for map_range in &self.ranges {
+            let source_end = map_range.source_start + map_range.size;
+            let shift = map_range.dest_start - map_range.source_start;
+            let mut rest = vec![];
+            for range in unmapped {
+                let start = range.start.max(map_range.source_start);
+                let end = range.end.min(source_end);
+                if start >= end {
+                    rest.push(range);
+                    continue;
+                }
+
+                mapped.push(start + shift..end + shift);
+                if range.start < start {
+                    rest.push(range.start..start);
+                }
+                if end < range.end {
+                    rest.push(end..range.end);
+                }
+            }
+            unmapped = rest;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: HashMap<String, Map>,
+}
+
+fn find_location(seed: i64, maps: &HashMap<String, Map>) -> Result<i64> {
+    let mut current_number = seed;
+    let mut current_kind = "seed";
+
+    while current_kind != "location" {
+        let map = maps
+            .get(current_kind)
+            .ok_or_else(|| anyhow!("no map from {}", current_kind))?;
+        current_kind = &map.to;
+        current_number = map.resolve(current_number);
+    }
+
+    Ok(current_number)
+}
+
+/// Pushes whole ranges of seeds through every map, returning the ranges of
+/// locations they end up at.
+fn find_locations(seeds: Vec<Range<i64>>, maps: &HashMap<String, Map>) -> Result<Vec<Range<i64>>> {
+    let mut current_ranges = seeds;
+    let mut current_kind = "seed";
+
+    while current_kind != "location" {
+        let map = maps
+            .get(current_kind)
+            .ok_or_else(|| anyhow!("no map from {}", current_kind))?;
+        current_kind = &map.to;
+        current_ranges = current_ranges
+            .into_iter()
+            .flat_map(|range| map.resolve_range(range))
+            .collect::<Vec<Range<i64>>>();
+        log::debug!("{} ranges of {}", current_ranges.len(), current_kind);
+    }
+
+    Ok(current_ranges)
+}
+
+/// The first block, a single line listing the seeds.
+fn parse_seeds(block: &[Line]) -> Result<Vec<i64>, ParseError> {
+    let line = block[0];
+    if let Some(extra) = block.get(1) {
+        return Err(extra.error_at(extra, "a blank line"));
+    }
+    line.integers_after(&line, "seeds:")
+}
+
+/// A block made of a map header followed by its ranges.
+fn parse_map(block: &[Line]) -> Result<Map, ParseError> {
+    let header = block[0];
+    let (from, to) = header
+        .strip_suffix(" map:")
+        .and_then(|name| name.split_once("-to-"))
+        .ok_or_else(|| header.error_at(&header, "a map header"))?;
+
+    let mut map = Map::new(from.to_owned(), to.to_owned());
+    for line in &block[1..] {
+        let [dest_start, source_start, size] = line.split_integer_array(line)?;
+        map.add_range(dest_start, source_start, size);
+    }
+    Ok(map)
+}
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let mut blocks = input.blocks();
+        let seeds = blocks
+            .next()
+            .ok_or_else(|| input.error_at_end("'seeds: '"))
+            .and_then(|block| parse_seeds(&block))?;
+        let maps = blocks
+            .map(|block| parse_map(&block).map(|map| (map.from.clone(), map)))
+            .collect::<Result<HashMap<String, Map>, ParseError>>()?;
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut lowest_seed_number = 9999999999;
+        for seed in &input.seeds {
+            let location_number = find_location(*seed, &input.maps)?;
+            log::trace!("seed {} is at location {}", seed, location_number);
+            if location_number < lowest_seed_number {
+                lowest_seed_number = location_number;
+            }
+        }
+
+        Ok(lowest_seed_number.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        if input.seeds.len() % 2 != 0 {
+            bail!("seed numbers should come in start and length pairs");
+        }
+        let seeds = input
+            .seeds
+            .chunks(2)
+            .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+            .collect();
+
+        let lowest = find_locations(seeds, &input.maps)?
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .ok_or_else(|| anyhow!("no seeds"))?;
+        Ok(lowest.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day5);
+
+    /// Part 2 the slow way, one seed at a time.
+    fn brute_force_part2(input: &Almanac) -> i64 {
+        input
+            .seeds
+            .chunks(2)
+            .flat_map(|chunk| chunk[0]..chunk[0] + chunk[1])
+            .map(|seed| find_location(seed, &input.maps).unwrap())
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_part2_matches_brute_force() {
+        let input = Day5::parse(&PuzzleInput::from_file("sample.txt").unwrap()).unwrap();
+        let expected = Answer::from(brute_force_part2(&input));
+        assert_eq!(expected, Day5::part2(&input).unwrap());
+    }
+
+    #[test]
+    fn test_resolve_range() {
+        let mut map = Map::new("seed".into(), "soil".into());
+        map.add_range(50, 98, 2);
+        map.add_range(52, 50, 48);
+
+        let expected = vec![50..52, 52..100, 46..50, 100..101];
+        assert_eq!(expected, map.resolve_range(46..101));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:4:1: expected 3 numbers, found 2 numbers";
+        let input = PuzzleInput::new("bad.txt", "seeds: 79 14\n\nseed-to-soil map:\n50 98\n");
+        let actual = Day5::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:3:1: expected a map header, found '50'";
+        let input = PuzzleInput::new("bad.txt", "seeds: 79 14\n\n50 98 2\n");
+        let actual = Day5::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:1:1: expected 'seeds:', found '50'";
+        let input = PuzzleInput::new("bad.txt", "50 98 2\n");
+        let actual = Day5::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use aoc_common::{Answer, PuzzleInput, Solution};
+
+pub struct Day6;
+
+pub struct Race {
+    time: i64,
+    distance: i64,
+}
+
+impl Race {
+    fn winners(&self) -> i64 {
+        let mut winners = 0;
+
+        for millimeters_per_second in 0..(self.time + 1) {
+            let remaining_time = self.time - millimeters_per_second;
+            let travelled = remaining_time * millimeters_per_second;
+            if travelled > self.distance {
+                winners += 1;
+            }
+        }
+
+        winners
+    }
+}
+
+impl Solution for Day6 {
+    type Input = Vec<Race>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let mut lines = input.lines();
+        let times_line = lines
+            .next()
+            .ok_or_else(|| input.error_at_end("a 'Time:' line"))?;
+        let distances_line = lines
+            .next()
+            .ok_or_else(|| input.error_at_end("a 'Distance:' line"))?;
+
+        let times = times_line.integers_after::<i64>(&times_line, "Time:")?;
+        let distances = distances_line.integers_after::<i64>(&distances_line, "Distance:")?;
+        if times.len() != distances.len() {
+            return Err(distances_line
+                .error(0, format!("{} distances", times.len()), distances.len())
+                .into());
+        }
+
+        Ok(times
+            .iter()
+            .zip(distances)
+            .map(|(time, distance)| Race {
+                time: *time,
+                distance,
+            })
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .map(|race| race.winners())
+            .product::<i64>()
+            .into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        // The races are really one race whose numbers were split up by the
+        // kerning, so glue the digits back together.
+        let time = input
+            .iter()
+            .map(|race| race.time.to_string())
+            .collect::<String>()
+            .parse::<i64>()?;
+        let distance = input
+            .iter()
+            .map(|race| race.distance.to_string())
+            .collect::<String>()
+            .parse::<i64>()?;
+
+        Ok(Race { time, distance }.winners().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day6);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:1: expected 3 distances, found 2";
+        let input = PuzzleInput::new("bad.txt", "Time: 7 15 30\nDistance: 9 40\n");
+        let actual = Day6::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:2:1: expected a 'Distance:' line, found end of input";
+        let input = PuzzleInput::new("bad.txt", "Time: 7 15 30\n");
+        let actual = Day6::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
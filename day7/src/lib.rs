@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use aoc_common::{Answer, Line, ParseError, PuzzleInput, Solution};
+
+pub struct Day7;
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Debug)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        Ok(match value {
+            'A' => Card::A,
+            'K' => Card::K,
+            'Q' => Card::Q,
+            'J' => Card::J,
+            'T' => Card::T,
+            '9' => Card::Nine,
+            '8' => Card::Eight,
+            '7' => Card::Seven,
+            '6' => Card::Six,
+            '5' => Card::Five,
+            '4' => Card::Four,
+            '3' => Card::Three,
+            '2' => Card::Two,
+            _ => bail!("{} isn't a valid card", value),
+        })
+    }
+}
+
+fn calculate_category(cards: &[Card]) -> Category {
+    let mut card_types: HashMap<Card, i64> = HashMap::new();
+    let mut joker_count = 0;
+    let mut most_frequent_card = Card::Joker;
+    let mut most_frequent_card_count = 0_i64;
+    for card in cards {
+        if card == &Card::Joker {
+            joker_count += 1;
+            continue;
+        }
+
+        if let Some(count) = card_types.get_mut(card) {
+            *count += 1;
+            if *count > most_frequent_card_count {
+                most_frequent_card = card.clone();
+                most_frequent_card_count = *count;
+            }
+        } else {
+            card_types.insert(card.clone(), 1);
+            if 1 > most_frequent_card_count {
+                most_frequent_card = card.clone();
+                most_frequent_card_count = 1;
+            }
+        }
+    }
+
+    if joker_count == 5 {
+        return Category::FiveOfAKind;
+    }
+
+    if joker_count > 0 {
+        if let Some(cc) = card_types.get_mut(&most_frequent_card) {
+            *cc += joker_count;
+        }
+    }
+
+    match card_types.len() {
+        5 => Category::HighCard,
+        4 => Category::OnePair,
+        3 => {
+            if card_types.values().any(|v| v == &3) {
+                Category::ThreeOfAKind
+            } else {
+                Category::TwoPair
+            }
+        }
+        2 => {
+            if card_types.values().any(|v| v == &4) {
+                Category::FourOfAKind
+            } else {
+                Category::FullHouse
+            }
+        }
+        1 => Category::FiveOfAKind,
+        _ => unreachable!("don't know what to do with {} card types", card_types.len()),
+    }
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
+enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+// Field order matters: hands are ranked by category first, then card by card.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Hand {
+    category: Category,
+    cards: Vec<Card>,
+}
+
+impl Hand {
+    fn new(cards: Vec<Card>) -> Self {
+        let category = calculate_category(&cards);
+        Hand { category, cards }
+    }
+}
+
+pub struct Round {
+    cards: Vec<Card>,
+    bid: i64,
+}
+
+impl TryFrom<Line<'_>> for Round {
+    type Error = anyhow::Error;
+
+    fn try_from(line: Line<'_>) -> Result<Self> {
+        let (hand, bid) = line
+            .split_once(' ')
+            .ok_or_else(|| line.error_at("", "a bid"))?;
+        let cards = hand
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                Card::try_from(c).map_err(|_| line.error(i, "a card", format!("'{}'", c)))
+            })
+            .collect::<Result<Vec<Card>, ParseError>>()?;
+        if cards.len() != 5 {
+            return Err(line.error_at(hand, "5 cards").into());
+        }
+
+        Ok(Round {
+            cards,
+            bid: line.parse(bid, "a bid")?,
+        })
+    }
+}
+
+fn total_winnings(mut hands: Vec<(Hand, i64)>) -> i64 {
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| bid * (i + 1) as i64)
+        .sum::<i64>()
+}
+
+impl Solution for Day7 {
+    type Input = Vec<Round>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        input.lines().map(Round::try_from).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(total_winnings(
+            input
+                .iter()
+                .map(|r| (Hand::new(r.cards.clone()), r.bid))
+                .collect(),
+        )
+        .into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        // Jacks become jokers: wild for the category, weakest when comparing.
+        let jokers = |cards: &[Card]| {
+            cards
+                .iter()
+                .map(|c| {
+                    if c == &Card::J {
+                        Card::Joker
+                    } else {
+                        c.clone()
+                    }
+                })
+                .collect::<Vec<Card>>()
+        };
+
+        Ok(total_winnings(
+            input
+                .iter()
+                .map(|r| (Hand::new(jokers(&r.cards)), r.bid))
+                .collect(),
+        )
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day7);
+
+    #[test]
+    fn test_card_sorting() {
+        assert!(Card::A > Card::K);
+        assert!(Card::K > Card::Q);
+        assert!(Card::Q > Card::J);
+        assert!(Card::A > Card::J);
+        assert!(Card::Two > Card::Joker);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:4: expected a card, found 'X'";
+        let input = PuzzleInput::new("bad.txt", "32T3K 765\nT55XJ 684\n");
+        let actual = Day7::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:1:7: expected a bid, found 'abc'";
+        let input = PuzzleInput::new("bad.txt", "32T3K abc\n");
+        let actual = Day7::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::{
+    math,
+    search::{self, Cycle},
+    Answer, PuzzleInput, Solution,
+};
+
+pub struct Day8;
+
+struct Node {
+    left: String,
+    right: String,
+}
+
+pub struct Network {
+    pattern: Vec<char>,
+    nodes: HashMap<String, Node>,
+}
+
+/// Where a walk through the network is: the node, and how far along the
+/// pattern it has got.
+type State<'a> = (&'a str, usize);
+
+impl Network {
+    fn step<'a>(&'a self, (node, i): State<'a>) -> State<'a> {
+        let node = &self.nodes[node];
+        let next = match self.pattern[i] {
+            'L' => &node.left,
+            _ => &node.right,
+        };
+        (next, (i + 1) % self.pattern.len())
+    }
+}
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        let mut nodes: HashMap<String, Node> = HashMap::new();
+        let mut lines = input.lines();
+        let pattern_line = lines
+            .next()
+            .ok_or_else(|| input.error_at_end("a pattern of 'L' and 'R'"))?;
+        let pattern: Vec<char> = pattern_line.chars().collect();
+        if let Some(i) = pattern.iter().position(|c| *c != 'L' && *c != 'R') {
+            return Err(pattern_line
+                .error(i, "'L' or 'R'", format!("'{}'", pattern[i]))
+                .into());
+        }
+
+        let mut children_seen = vec![];
+        for line in lines {
+            let (ident, children) = line
+                .as_str()
+                .split_once(" = ")
+                .ok_or_else(|| line.error_at(&line, "'<node> = (<left>, <right>)'"))?;
+            let (left, right) = children
+                .strip_prefix('(')
+                .and_then(|c| c.strip_suffix(')'))
+                .and_then(|c| c.split_once(", "))
+                .ok_or_else(|| line.error_at(children, "'(<left>, <right>)'"))?;
+
+            children_seen.push((line, left));
+            children_seen.push((line, right));
+            nodes.insert(
+                ident.to_owned(),
+                Node {
+                    left: left.to_owned(),
+                    right: right.to_owned(),
+                },
+            );
+        }
+
+        for (line, child) in children_seen {
+            if !nodes.contains_key(child) {
+                return Err(line.error_on(child, "a known node").into());
+            }
+        }
+
+        Ok(Network { pattern, nodes })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        if !input.nodes.contains_key("AAA") {
+            bail!("there is no node AAA");
+        }
+        let walk = search::find_cycle(("AAA", 0), |state| input.step(*state));
+        let steps = walk
+            .states
+            .iter()
+            .position(|(node, _)| *node == "ZZZ")
+            .ok_or_else(|| anyhow!("the walk from AAA never reaches ZZZ"))?;
+        Ok(steps.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let ghosts = input
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|k| search::find_cycle((k.as_str(), 0), |state| input.step(*state)))
+            .collect::<Vec<Cycle<State>>>();
+        if ghosts.is_empty() {
+            bail!("no starting nodes, none of the nodes ends in A");
+        }
+        let on_z = |ghost: &Cycle<State>, steps: usize| ghost.nth(steps).0.ends_with('Z');
+
+        // Until every ghost has reached its cycle there's nothing to do but
+        // check each step.
+        let settled = ghosts.iter().map(|ghost| ghost.start).max().unwrap_or(0);
+        if let Some(steps) = (1..settled).find(|steps| ghosts.iter().all(|g| on_z(g, *steps))) {
+            return Ok(steps.into());
+        }
+
+        // From then on each ghost is on a Z node at fixed points in its cycle,
+        // so try every combination of those points.
+        let mut candidates = vec![(0, 1)];
+        for ghost in &ghosts {
+            let length = ghost.length as i64;
+            let offsets = (ghost.start..ghost.states.len())
+                .filter(|steps| on_z(ghost, *steps))
+                .map(|steps| steps as i64 % length)
+                .collect::<Vec<i64>>();
+            candidates = candidates
+                .iter()
+                .flat_map(|&candidate| {
+                    let offsets = offsets.iter();
+                    offsets.filter_map(move |&offset| math::crt([candidate, (offset, length)]))
+                })
+                .collect();
+        }
+
+        let settled = settled.max(1) as i64;
+        let steps = candidates
+            .into_iter()
+            .map(|(steps, modulus)| {
+                let short = (settled - steps).max(0);
+                steps + (short + modulus - 1) / modulus * modulus
+            })
+            .min()
+            .ok_or_else(|| {
+                anyhow!("the ghosts are never all on Z nodes within an i64 number of steps")
+            })?;
+        Ok(steps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day8);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:1:3: expected 'L' or 'R', found 'X'";
+        let input = PuzzleInput::new("bad.txt", "LLX\n\nAAA = (AAA, AAA)\n");
+        let actual = Day8::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+
+        let expected = "bad.txt:3:13: expected a known node, found 'ZZZ'";
+        let input = PuzzleInput::new("bad.txt", "LR\n\nAAA = (AAA, ZZZ)\n");
+        let actual = Day8::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+
+    #[test]
+    fn test_part2_without_starting_nodes() {
+        let input = PuzzleInput::new(
+            "t.txt",
+            "L
+
+BBB = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+",
+        );
+        let input = Day8::parse(&input).unwrap();
+        let actual = Day8::part2(&input).err().unwrap();
+        assert_eq!(
+            "no starting nodes, none of the nodes ends in A",
+            actual.to_string()
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use aoc_common::{Answer, ParseError, PuzzleInput, Solution};
+
+pub struct Day9;
+
+/// Repeatedly takes differences until they are all zero, returning every run
+/// from the deepest back up to the original history.
+fn decompose(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut current_nums = history.to_vec();
+    let mut runs: Vec<Vec<i64>> = vec![current_nums.clone()];
+    let mut decomposing = true;
+
+    while decomposing {
+        let mut differences: Vec<i64> = vec![];
+        for (i, num) in current_nums.iter().enumerate() {
+            let next_num = current_nums.get(i + 1);
+            if next_num.is_none() {
+                continue;
+            }
+
+            differences.push(next_num.unwrap() - num);
+        }
+
+        if differences.iter().all(|n| n == &0) {
+            decomposing = false;
+        }
+
+        current_nums = differences.clone();
+        runs.push(differences);
+    }
+
+    runs.reverse();
+    runs
+}
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.split_integers(&line))
+            .collect::<Result<Self::Input, ParseError>>()
+            .map_err(Into::into)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+
+        for history in input {
+            let mut next_number = 0;
+            for run in decompose(history).iter().skip(1) {
+                let last_number = run.last().unwrap();
+                next_number += last_number;
+            }
+
+            sum += next_number
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut sum = 0;
+
+        for history in input {
+            let mut previous_number = 0;
+            for run in decompose(history).iter().skip(1) {
+                let first_number = run.first().unwrap();
+                previous_number = first_number - previous_number;
+            }
+
+            sum += previous_number
+        }
+
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_macros::sample_tests!(Day9);
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "bad.txt:2:5: expected a number, found '1.5'";
+        let input = PuzzleInput::new("bad.txt", "0 3 6\n1 3 1.5\n");
+        let actual = Day9::parse(&input).err().unwrap();
+        assert_eq!(expected, actual.to_string());
+    }
+}
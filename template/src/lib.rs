@@ -0,0 +1,20 @@
+use anyhow::Result;
+use aoc_common::{PuzzleInput, Solution};
+
+pub struct DayREPLACE_ME_DAY_NUMBER;
+
+impl Solution for DayREPLACE_ME_DAY_NUMBER {
+    type Input = Vec<String>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    // part1 and part2 report themselves as unimplemented until they're
+    // written here.
+}
+
+#[cfg(test)]
+mod tests {
+    aoc_macros::sample_tests!(super::DayREPLACE_ME_DAY_NUMBER);
+}